@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::objects::{AudiobookObject, PagingObject, SimplifiedChapterObject};
+use crate::{insert_optional, Error, Market, Spotify};
+
+/// The most audiobook ids a single request accepts.
+pub const MAX_AUDIOBOOK_IDS: usize = 50;
+
+#[derive(Deserialize)]
+struct Audiobooks {
+    audiobooks: Vec<Option<AudiobookObject>>,
+}
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-an-audiobook)
+    pub fn get_audiobook(
+        &self,
+        id: &str,
+        market: Option<Market>,
+    ) -> Result<AudiobookObject, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "market", market);
+
+        self.get(format!("audiobooks/{}", id), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-multiple-audiobooks)
+    ///
+    /// Requests the audiobooks [`MAX_AUDIOBOOK_IDS`] at a time. Audiobooks that are not found are `None`.
+    pub fn get_several_audiobooks(
+        &self,
+        ids: &[&str],
+        market: Option<Market>,
+    ) -> Result<Vec<Option<AudiobookObject>>, Error> {
+        let mut audiobooks = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(MAX_AUDIOBOOK_IDS) {
+            let mut params = HashMap::new();
+            params.insert("ids", chunk.join(","));
+            insert_optional(&mut params, "market", market);
+
+            let page: Audiobooks = self.get("audiobooks".to_string(), Some(params))?;
+            audiobooks.extend(page.audiobooks);
+        }
+        Ok(audiobooks)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-audiobook-chapters)
+    pub fn get_audiobook_chapters(
+        &self,
+        id: &str,
+        market: Option<Market>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<SimplifiedChapterObject>, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "market", market);
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "offset", offset);
+
+        self.get(format!("audiobooks/{}/chapters", id), Some(params))
+    }
+}
@@ -1,2 +1,116 @@
+use serde::Serialize;
+
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Nothing;
+
+/// Where playback starts within a context.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Offset {
+    /// The zero-based index of the item in the context.
+    Position(usize),
+    /// The URI of the item in the context.
+    Uri(String),
+}
+
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-start-a-users-playback)
+#[derive(Clone, Debug, Default, Serialize, PartialEq, Eq)]
+pub struct StartPlaybackBody {
+    /// The URI of the album, artist or playlist to play.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_uri: Option<String>,
+    /// The track URIs to play.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uris: Option<Vec<String>>,
+    /// Only valid together with `context_uri` for an album or playlist.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<Offset>,
+    /// Starts playback at this position in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position_ms: Option<usize>,
+}
+
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-transfer-a-users-playback)
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct TransferPlaybackBody {
+    /// The device to transfer playback to. Only a single device is supported.
+    pub device_ids: Vec<String>,
+    /// `true` ensures playback happens on the new device, `false` keeps the current playback state.
+    pub play: bool,
+}
+
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-create-playlist)
+#[derive(Clone, Debug, Default, Serialize, PartialEq, Eq)]
+pub struct PlaylistDetailsBody {
+    /// The name of the playlist. Required when creating a playlist.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// `true` makes the playlist public, `false` private.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+    /// `true` lets other users modify the playlist. Only a private playlist can be collaborative.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collaborative: Option<bool>,
+    /// The description displayed in Spotify clients.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-add-tracks-to-playlist)
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct AddItemsBody {
+    /// The track or episode URIs to add.
+    pub uris: Vec<String>,
+    /// The zero-based position to insert the items at. The items are appended if not given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<usize>,
+}
+
+/// An item to remove from a playlist.
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct RemoveItem {
+    /// The track or episode URI.
+    pub uri: String,
+    /// The zero-based positions of the occurrences to remove. Every occurrence is removed if not given.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub positions: Option<Vec<usize>>,
+}
+
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-remove-tracks-playlist)
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct RemoveItemsBody {
+    pub tracks: Vec<RemoveItem>,
+    /// The playlist version the removal applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<String>,
+}
+
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-reorder-or-replace-playlists-tracks)
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct ReorderItemsBody {
+    /// The position of the first item to move.
+    pub range_start: usize,
+    /// The position to move the items before.
+    pub insert_before: usize,
+    /// The number of items to move. Defaults to 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range_length: Option<usize>,
+    /// The playlist version the reordering applies to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot_id: Option<String>,
+}
+
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-reorder-or-replace-playlists-tracks)
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct ReplaceItemsBody {
+    /// The track or episode URIs the playlist is left with.
+    pub uris: Vec<String>,
+}
+
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-follow-playlist)
+#[derive(Clone, Debug, Serialize, PartialEq, Eq)]
+pub struct FollowPlaylistBody {
+    /// `true` lists the playlist on the user's profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public: Option<bool>,
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::objects::{CategoryObject, PagingObject, SimplifiedPlaylistObject};
+use crate::{insert_optional, Error, Market, Spotify};
+
+/// A page of featured playlists along with the message displayed above them.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FeaturedPlaylists {
+    /// The localized message of the day, such as “Good evening”.
+    pub message: Option<String>,
+    pub playlists: PagingObject<SimplifiedPlaylistObject>,
+}
+
+#[derive(Deserialize)]
+struct Categories {
+    categories: PagingObject<CategoryObject>,
+}
+
+#[derive(Deserialize)]
+struct Playlists {
+    playlists: PagingObject<SimplifiedPlaylistObject>,
+}
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-categories)
+    ///
+    /// `locale` is an ISO 639-1 language code joined with an ISO 3166-1 alpha-2 country code, for example
+    /// `es_MX`.
+    pub fn get_categories(
+        &self,
+        country: Option<Market>,
+        locale: Option<&str>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<CategoryObject>, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "country", country);
+        insert_optional(&mut params, "locale", locale);
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "offset", offset);
+
+        let categories: Categories = self.get("browse/categories".to_string(), Some(params))?;
+        Ok(categories.categories)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-a-category)
+    pub fn get_category(
+        &self,
+        category_id: &str,
+        country: Option<Market>,
+        locale: Option<&str>,
+    ) -> Result<CategoryObject, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "country", country);
+        insert_optional(&mut params, "locale", locale);
+
+        self.get(format!("browse/categories/{}", category_id), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-a-categories-playlists)
+    pub fn get_category_playlists(
+        &self,
+        category_id: &str,
+        country: Option<Market>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<SimplifiedPlaylistObject>, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "country", country);
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "offset", offset);
+
+        let playlists: Playlists = self.get(
+            format!("browse/categories/{}/playlists", category_id),
+            Some(params),
+        )?;
+        Ok(playlists.playlists)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-featured-playlists)
+    ///
+    /// `timestamp` is an ISO 8601 local time such as `2014-10-23T09:00:00`, used to pick the playlists
+    /// relevant to that time of day. Defaults to the current UTC time.
+    pub fn get_featured_playlists(
+        &self,
+        country: Option<Market>,
+        locale: Option<&str>,
+        timestamp: Option<&str>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<FeaturedPlaylists, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "country", country);
+        insert_optional(&mut params, "locale", locale);
+        insert_optional(&mut params, "timestamp", timestamp);
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "offset", offset);
+
+        self.get("browse/featured-playlists".to_string(), Some(params))
+    }
+}
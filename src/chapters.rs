@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::objects::ChapterObject;
+use crate::{insert_optional, Error, Market, Spotify};
+
+/// The most chapter ids a single request accepts.
+pub const MAX_CHAPTER_IDS: usize = 50;
+
+#[derive(Deserialize)]
+struct Chapters {
+    chapters: Vec<Option<ChapterObject>>,
+}
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-a-chapter)
+    ///
+    /// `resume_point` is set if the token has the `user-read-playback-position` scope.
+    pub fn get_chapter(&self, id: &str, market: Option<Market>) -> Result<ChapterObject, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "market", market);
+
+        self.get(format!("chapters/{}", id), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-several-chapters)
+    ///
+    /// Requests the chapters [`MAX_CHAPTER_IDS`] at a time. Chapters that are not found are `None`.
+    pub fn get_several_chapters(
+        &self,
+        ids: &[&str],
+        market: Option<Market>,
+    ) -> Result<Vec<Option<ChapterObject>>, Error> {
+        let mut chapters = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(MAX_CHAPTER_IDS) {
+            let mut params = HashMap::new();
+            params.insert("ids", chunk.join(","));
+            insert_optional(&mut params, "market", market);
+
+            let page: Chapters = self.get("chapters".to_string(), Some(params))?;
+            chapters.extend(page.chapters);
+        }
+        Ok(chapters)
+    }
+}
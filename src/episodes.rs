@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::objects::EpisodeObject;
+use crate::{insert_optional, Error, Market, Spotify};
+
+/// The most episode ids a single request accepts.
+pub const MAX_EPISODE_IDS: usize = 50;
+
+#[derive(Deserialize)]
+struct Episodes {
+    episodes: Vec<Option<EpisodeObject>>,
+}
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-an-episode)
+    ///
+    /// `resume_point` is set if the token has the `user-read-playback-position` scope.
+    pub fn get_episode(&self, id: &str, market: Option<Market>) -> Result<EpisodeObject, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "market", market);
+
+        self.get(format!("episodes/{}", id), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-multiple-episodes)
+    ///
+    /// Requests the episodes [`MAX_EPISODE_IDS`] at a time. Episodes that are not found are `None`.
+    pub fn get_several_episodes(
+        &self,
+        ids: &[&str],
+        market: Option<Market>,
+    ) -> Result<Vec<Option<EpisodeObject>>, Error> {
+        let mut episodes = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(MAX_EPISODE_IDS) {
+            let mut params = HashMap::new();
+            params.insert("ids", chunk.join(","));
+            insert_optional(&mut params, "market", market);
+
+            let page: Episodes = self.get("episodes".to_string(), Some(params))?;
+            episodes.extend(page.episodes);
+        }
+        Ok(episodes)
+    }
+}
@@ -0,0 +1,80 @@
+use std::fmt;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::objects::{ErrorJSON, ErrorObject, PlayerErrorObject};
+
+/// Errors returned by the [`Spotify`](crate::Spotify) endpoint methods.
+#[derive(Debug)]
+pub enum Error {
+    /// The request could not be sent or the response could not be read.
+    Http(reqwest::Error),
+    /// The response body could not be decoded into the expected object.
+    Json(serde_json::Error),
+    /// The Web API answered with an [Error Object](https://developer.spotify.com/documentation/web-api/reference/#object-errorobject).
+    Api(ErrorObject),
+    /// A player endpoint answered with a [Player Error Object](https://developer.spotify.com/documentation/web-api/reference/#object-playererrorobject).
+    Player(PlayerErrorObject),
+    /// The Web API answered `429 Too Many Requests`; the request may be retried after the given delay.
+    RateLimited(Duration),
+    /// An argument was rejected before anything was sent to the Web API.
+    InvalidArgument(String),
+}
+
+#[derive(Deserialize)]
+struct PlayerErrorJSON {
+    error: PlayerErrorObject,
+}
+
+impl Error {
+    /// Builds an error from an unsuccessful response.
+    pub(crate) fn from_response(status: u16, body: &str) -> Self {
+        // Player endpoints explain a `403` or `404` with a reason.
+        if status == 403 || status == 404 {
+            if let Ok(json) = serde_json::from_str::<PlayerErrorJSON>(body) {
+                return Error::Player(json.error);
+            }
+        }
+        match serde_json::from_str::<ErrorJSON>(body) {
+            Ok(json) => Error::Api(json.error),
+            Err(_) => Error::Api(ErrorObject {
+                message: body.to_string(),
+                status: status as usize,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Http(err) => write!(f, "http error: {}", err),
+            Error::Json(err) => write!(f, "json error: {}", err),
+            Error::Api(err) => write!(f, "api error {}: {}", err.status, err.message),
+            Error::Player(err) => write!(
+                f,
+                "player error {}: {} ({})",
+                err.status, err.message, err.reason
+            ),
+            Error::RateLimited(retry_after) => {
+                write!(f, "rate limited, retry after {:?}", retry_after)
+            }
+            Error::InvalidArgument(message) => write!(f, "invalid argument: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Http(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::body::{FollowPlaylistBody, Nothing};
+use crate::objects::{ArtistObject, CursorPagingObject};
+use crate::{insert_optional, Error, Method, Spotify};
+
+/// The most artist or user ids a single follow request accepts.
+pub const MAX_FOLLOW_IDS: usize = 50;
+/// The most user ids a single playlist follower check accepts.
+pub const MAX_PLAYLIST_FOLLOWER_IDS: usize = 5;
+
+#[derive(Deserialize)]
+struct Artists {
+    artists: CursorPagingObject<ArtistObject>,
+}
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-follow-artists-users)
+    pub fn follow_artists(&self, ids: &[&str]) -> Result<(), Error> {
+        self.change_following(Method::PUT, "artist", ids)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-follow-artists-users)
+    pub fn follow_users(&self, ids: &[&str]) -> Result<(), Error> {
+        self.change_following(Method::PUT, "user", ids)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-unfollow-artists-users)
+    pub fn unfollow_artists(&self, ids: &[&str]) -> Result<(), Error> {
+        self.change_following(Method::DELETE, "artist", ids)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-unfollow-artists-users)
+    pub fn unfollow_users(&self, ids: &[&str]) -> Result<(), Error> {
+        self.change_following(Method::DELETE, "user", ids)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-check-current-user-follows)
+    ///
+    /// Returns each id along with whether it is followed, in the order given.
+    pub fn check_following_artists(&self, ids: &[&str]) -> Result<Vec<(String, bool)>, Error> {
+        self.check_following("artist", ids)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-check-current-user-follows)
+    ///
+    /// Returns each id along with whether it is followed, in the order given.
+    pub fn check_following_users(&self, ids: &[&str]) -> Result<Vec<(String, bool)>, Error> {
+        self.check_following("user", ids)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-followed)
+    ///
+    /// `after` is the last artist id of the previous page, found in its `cursors`.
+    pub fn get_followed_artists(
+        &self,
+        limit: Option<usize>,
+        after: Option<&str>,
+    ) -> Result<CursorPagingObject<ArtistObject>, Error> {
+        let mut params = HashMap::new();
+        params.insert("type", "artist".to_string());
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "after", after);
+
+        let artists: Artists = self.get("me/following".to_string(), Some(params))?;
+        Ok(artists.artists)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-follow-playlist)
+    ///
+    /// `public` lists the playlist on the user's profile, which is the default.
+    pub fn follow_playlist(&self, playlist_id: &str, public: Option<bool>) -> Result<(), Error> {
+        self.request(
+            Method::PUT,
+            format!("playlists/{}/followers", playlist_id),
+            None,
+            Some(&FollowPlaylistBody { public }),
+        )?;
+        Ok(())
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-unfollow-playlist)
+    pub fn unfollow_playlist(&self, playlist_id: &str) -> Result<(), Error> {
+        self.request::<Nothing>(
+            Method::DELETE,
+            format!("playlists/{}/followers", playlist_id),
+            None,
+            None,
+        )?;
+        Ok(())
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-check-if-user-follows-playlist)
+    ///
+    /// Returns each user id along with whether the user follows the playlist, in the order given.
+    pub fn check_users_follow_playlist(
+        &self,
+        playlist_id: &str,
+        user_ids: &[&str],
+    ) -> Result<Vec<(String, bool)>, Error> {
+        self.check_ids(
+            &format!("playlists/{}/followers/contains", playlist_id),
+            user_ids,
+            MAX_PLAYLIST_FOLLOWER_IDS,
+        )
+    }
+
+    fn change_following(&self, method: Method, kind: &str, ids: &[&str]) -> Result<(), Error> {
+        let mut params = HashMap::new();
+        params.insert("type", kind.to_string());
+        self.request_ids_with(method, "me/following", params, ids, MAX_FOLLOW_IDS)
+    }
+
+    fn check_following(&self, kind: &str, ids: &[&str]) -> Result<Vec<(String, bool)>, Error> {
+        let mut params = HashMap::new();
+        params.insert("type", kind.to_string());
+        self.check_ids_with("me/following/contains", params, ids, MAX_FOLLOW_IDS)
+    }
+}
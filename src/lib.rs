@@ -1,14 +1,100 @@
 use std::collections::HashMap;
+use std::time::Duration;
 
-use reqwest::Url;
+use reqwest::blocking::{Client, RequestBuilder};
+use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE, RETRY_AFTER};
+use reqwest::{StatusCode, Url};
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 
+/// Declares an enum for a string sent by the Web API, with an `Other` variant keeping any value added after
+/// this crate was written.
+macro_rules! string_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $value:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Debug, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// A value this crate does not know about yet.
+            Other(String),
+        }
+
+        impl $name {
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Other(value) => value,
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let value = String::deserialize(deserializer)?;
+                Ok(match value.as_str() {
+                    $($value => $name::$variant,)*
+                    _ => $name::Other(value),
+                })
+            }
+        }
+    };
+}
+
+pub mod audiobooks;
 pub mod body;
+pub mod browse;
+pub mod chapters;
+pub mod episodes;
+pub mod error;
+pub mod follow;
+pub mod library;
+pub mod market;
 pub mod objects;
+pub mod pagination;
+pub mod personalization;
+pub mod player;
+pub mod playlists;
+pub mod recommendations;
+pub mod release_date;
+pub mod search;
+pub mod shows;
+pub mod users;
+
+pub use error::Error;
+pub use market::Market;
+pub use release_date::ReleaseDate;
+
 pub struct Spotify {
     pub authorization: String,
+    /// Shared by every request, so that connections are reused.
+    client: Client,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Method {
     GET,
     POST,
@@ -16,85 +102,218 @@ pub enum Method {
     DELETE,
 }
 
-impl PartialEq for Method {
-    fn eq(&self, other: &Self) -> bool {
-        self == other
-    }
-}
-
 impl Spotify {
     pub fn new(authorization: &str) -> Self {
         Spotify {
             authorization: authorization.to_string(),
+            client: Client::new(),
         }
     }
 
+    /// Sends a request to the Web API and returns the response body, or `None` if the response has no content.
     pub fn request<T>(
         &self,
         method: Method,
         url: String,
         query: Option<HashMap<&str, String>>,
         body: Option<&T>,
-    ) -> Option<(String, bool)>
+    ) -> Result<Option<String>, Error>
     where
         T: Serialize + ?Sized,
     {
+        let builder = self.builder(method, url, query);
+
+        match body {
+            Some(body) => self.send(builder.json(body)),
+            // The Web API rejects a `POST` or `PUT` without a length with 411 Length Required.
+            None if method == Method::POST || method == Method::PUT => {
+                self.send(builder.header(CONTENT_LENGTH, 0))
+            }
+            None => self.send(builder),
+        }
+    }
+
+    /// Sends a request with a raw body, such as an image, and returns the response body.
+    pub(crate) fn request_bytes(
+        &self,
+        method: Method,
+        url: String,
+        content_type: &str,
+        body: Vec<u8>,
+    ) -> Result<Option<String>, Error> {
+        let builder = self
+            .builder(method, url, None)
+            .header(CONTENT_TYPE, content_type)
+            .body(body);
+        self.send(builder)
+    }
+
+    fn builder(
+        &self,
+        method: Method,
+        url: String,
+        query: Option<HashMap<&str, String>>,
+    ) -> RequestBuilder {
+        // Links returned by the Web API, such as `next`, are already absolute.
+        let url = if url.starts_with("https://") {
+            url
+        } else {
+            format!("https://api.spotify.com/v1/{}", url)
+        };
         let url = {
             if let Some(query) = query {
-                Url::parse_with_params(
-                    format!("https://api.spotify.com/v1/{}", url).as_str(),
-                    query,
-                )
-                .unwrap()
-                .to_string()
+                Url::parse_with_params(url.as_str(), query)
+                    .unwrap()
+                    .to_string()
             } else {
-                format!("https://api.spotify.com/v1/{}", url)
+                url
             }
         };
 
-        let client = reqwest::blocking::Client::new();
-
         match method {
-            Method::GET => {
-                let response = client
-                    .get(url)
-                    .header("Accept", "application/json")
-                    .header("Content-Type", "application/json")
-                    .bearer_auth(self.authorization.as_str())
-                    .send()
-                    .unwrap();
-                if response.status().is_success() {
-                    Some((response.text().unwrap(), true))
-                } else {
-                    Some((response.text().unwrap(), false))
-                }
-            }
-            Method::POST => {
-                client
-                    .post(url)
-                    .bearer_auth(self.authorization.as_str())
-                    .json(body.unwrap())
-                    .send()
-                    .unwrap();
-                None
-            }
-            Method::PUT => {
-                client
-                    .put(url)
-                    .bearer_auth(self.authorization.as_str())
-                    .json(body.unwrap())
-                    .send()
-                    .unwrap();
-                None
-            }
-            Method::DELETE => {
-                client
-                    .put(url)
-                    .bearer_auth(self.authorization.as_str())
-                    .send()
-                    .unwrap();
-                None
-            }
+            Method::GET => self.client.get(url),
+            Method::POST => self.client.post(url),
+            Method::PUT => self.client.put(url),
+            Method::DELETE => self.client.delete(url),
+        }
+    }
+
+    fn send(&self, builder: RequestBuilder) -> Result<Option<String>, Error> {
+        let response = builder
+            .header("Accept", "application/json")
+            .bearer_auth(self.authorization.as_str())
+            .send()?;
+        let status = response.status();
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(1);
+            return Err(Error::RateLimited(Duration::from_secs(retry_after)));
         }
+        let text = response.text()?;
+
+        if !status.is_success() {
+            return Err(Error::from_response(status.as_u16(), &text));
+        }
+        if text.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(text))
+        }
+    }
+
+    /// Sends a `GET` request and decodes the response body.
+    pub(crate) fn get<R>(
+        &self,
+        url: String,
+        query: Option<HashMap<&str, String>>,
+    ) -> Result<R, Error>
+    where
+        R: DeserializeOwned,
+    {
+        let text = self.request::<body::Nothing>(Method::GET, url, query, None)?;
+        Ok(serde_json::from_str(&text.unwrap_or_default())?)
+    }
+
+    /// Sends a request with a JSON body and decodes the response body.
+    pub(crate) fn request_json<T, R>(
+        &self,
+        method: Method,
+        url: String,
+        body: &T,
+    ) -> Result<R, Error>
+    where
+        T: Serialize + ?Sized,
+        R: DeserializeOwned,
+    {
+        let text = self.request(method, url, None, Some(body))?;
+        Ok(serde_json::from_str(&text.unwrap_or_default())?)
+    }
+
+    /// Sends the ids as the `ids` query parameter, `chunk_size` at a time.
+    pub(crate) fn request_ids(
+        &self,
+        method: Method,
+        url: &str,
+        ids: &[&str],
+        chunk_size: usize,
+    ) -> Result<(), Error> {
+        self.request_ids_with(method, url, HashMap::new(), ids, chunk_size)
+    }
+
+    /// Like [`request_ids`](Spotify::request_ids), with further query parameters sent along each chunk.
+    pub(crate) fn request_ids_with(
+        &self,
+        method: Method,
+        url: &str,
+        params: HashMap<&str, String>,
+        ids: &[&str],
+        chunk_size: usize,
+    ) -> Result<(), Error> {
+        for chunk in ids.chunks(chunk_size) {
+            let mut params = params.clone();
+            params.insert("ids", chunk.join(","));
+            self.request::<body::Nothing>(method, url.to_string(), Some(params), None)?;
+        }
+        Ok(())
+    }
+
+    /// Checks the ids, `chunk_size` at a time, and pairs each with its result.
+    pub(crate) fn check_ids(
+        &self,
+        url: &str,
+        ids: &[&str],
+        chunk_size: usize,
+    ) -> Result<Vec<(String, bool)>, Error> {
+        self.check_ids_with(url, HashMap::new(), ids, chunk_size)
+    }
+
+    /// Like [`check_ids`](Spotify::check_ids), with further query parameters sent along each chunk.
+    pub(crate) fn check_ids_with(
+        &self,
+        url: &str,
+        params: HashMap<&str, String>,
+        ids: &[&str],
+        chunk_size: usize,
+    ) -> Result<Vec<(String, bool)>, Error> {
+        let mut checked = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(chunk_size) {
+            let mut params = params.clone();
+            params.insert("ids", chunk.join(","));
+            let results: Vec<bool> = self.get(url.to_string(), Some(params))?;
+            checked.extend(chunk.iter().map(|id| id.to_string()).zip(results));
+        }
+        Ok(checked)
+    }
+
+    /// Sends a `GET` request and decodes the response body, or returns `None` if the response has no content.
+    pub(crate) fn get_optional<R>(
+        &self,
+        url: String,
+        query: Option<HashMap<&str, String>>,
+    ) -> Result<Option<R>, Error>
+    where
+        R: DeserializeOwned,
+    {
+        match self.request::<body::Nothing>(Method::GET, url, query, None)? {
+            Some(text) => Ok(Some(serde_json::from_str(&text)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Inserts a query parameter only if it is set.
+pub(crate) fn insert_optional<'a, V>(
+    params: &mut HashMap<&'a str, String>,
+    key: &'a str,
+    value: Option<V>,
+) where
+    V: ToString,
+{
+    if let Some(value) = value {
+        params.insert(key, value.to_string());
     }
 }
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+
+use crate::objects::{
+    PagingObject, SavedAlbumObject, SavedEpisodeObject, SavedShowObject, SavedTrackObject,
+    SimplifiedAudiobookObject,
+};
+use crate::{insert_optional, Error, Market, Method, Spotify};
+
+/// The most album ids a single library request accepts.
+pub const MAX_ALBUM_IDS: usize = 20;
+/// The most track, show, episode or audiobook ids a single library request accepts.
+pub const MAX_IDS: usize = 50;
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-users-saved-tracks)
+    pub fn get_saved_tracks(
+        &self,
+        market: Option<Market>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<SavedTrackObject>, Error> {
+        self.get_saved("me/tracks", market, limit, offset)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-save-tracks-user)
+    pub fn save_tracks(&self, ids: &[&str]) -> Result<(), Error> {
+        self.request_ids(Method::PUT, "me/tracks", ids, MAX_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-remove-tracks-user)
+    pub fn remove_saved_tracks(&self, ids: &[&str]) -> Result<(), Error> {
+        self.request_ids(Method::DELETE, "me/tracks", ids, MAX_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-check-users-saved-tracks)
+    ///
+    /// Returns each id along with whether it is saved, in the order given.
+    pub fn check_saved_tracks(&self, ids: &[&str]) -> Result<Vec<(String, bool)>, Error> {
+        self.check_ids("me/tracks/contains", ids, MAX_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-users-saved-albums)
+    pub fn get_saved_albums(
+        &self,
+        market: Option<Market>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<SavedAlbumObject>, Error> {
+        self.get_saved("me/albums", market, limit, offset)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-save-albums-user)
+    pub fn save_albums(&self, ids: &[&str]) -> Result<(), Error> {
+        self.request_ids(Method::PUT, "me/albums", ids, MAX_ALBUM_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-remove-albums-user)
+    pub fn remove_saved_albums(&self, ids: &[&str]) -> Result<(), Error> {
+        self.request_ids(Method::DELETE, "me/albums", ids, MAX_ALBUM_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-check-users-saved-albums)
+    ///
+    /// Returns each id along with whether it is saved, in the order given.
+    pub fn check_saved_albums(&self, ids: &[&str]) -> Result<Vec<(String, bool)>, Error> {
+        self.check_ids("me/albums/contains", ids, MAX_ALBUM_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-users-saved-shows)
+    pub fn get_saved_shows(
+        &self,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<SavedShowObject>, Error> {
+        self.get_saved("me/shows", None, limit, offset)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-save-shows-user)
+    pub fn save_shows(&self, ids: &[&str]) -> Result<(), Error> {
+        self.request_ids(Method::PUT, "me/shows", ids, MAX_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-remove-shows-user)
+    pub fn remove_saved_shows(&self, ids: &[&str]) -> Result<(), Error> {
+        self.request_ids(Method::DELETE, "me/shows", ids, MAX_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-check-users-saved-shows)
+    ///
+    /// Returns each id along with whether it is saved, in the order given.
+    pub fn check_saved_shows(&self, ids: &[&str]) -> Result<Vec<(String, bool)>, Error> {
+        self.check_ids("me/shows/contains", ids, MAX_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-users-saved-episodes)
+    pub fn get_saved_episodes(
+        &self,
+        market: Option<Market>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<SavedEpisodeObject>, Error> {
+        self.get_saved("me/episodes", market, limit, offset)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-save-episodes-user)
+    pub fn save_episodes(&self, ids: &[&str]) -> Result<(), Error> {
+        self.request_ids(Method::PUT, "me/episodes", ids, MAX_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-remove-episodes-user)
+    pub fn remove_saved_episodes(&self, ids: &[&str]) -> Result<(), Error> {
+        self.request_ids(Method::DELETE, "me/episodes", ids, MAX_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-check-users-saved-episodes)
+    ///
+    /// Returns each id along with whether it is saved, in the order given.
+    pub fn check_saved_episodes(&self, ids: &[&str]) -> Result<Vec<(String, bool)>, Error> {
+        self.check_ids("me/episodes/contains", ids, MAX_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-users-saved-audiobooks)
+    pub fn get_saved_audiobooks(
+        &self,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<SimplifiedAudiobookObject>, Error> {
+        self.get_saved("me/audiobooks", None, limit, offset)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-save-audiobooks-user)
+    pub fn save_audiobooks(&self, ids: &[&str]) -> Result<(), Error> {
+        self.request_ids(Method::PUT, "me/audiobooks", ids, MAX_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-remove-audiobooks-user)
+    pub fn remove_saved_audiobooks(&self, ids: &[&str]) -> Result<(), Error> {
+        self.request_ids(Method::DELETE, "me/audiobooks", ids, MAX_IDS)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-check-users-saved-audiobooks)
+    ///
+    /// Returns each id along with whether it is saved, in the order given.
+    pub fn check_saved_audiobooks(&self, ids: &[&str]) -> Result<Vec<(String, bool)>, Error> {
+        self.check_ids("me/audiobooks/contains", ids, MAX_IDS)
+    }
+
+    fn get_saved<T>(
+        &self,
+        url: &str,
+        market: Option<Market>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<T>, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "market", market);
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "offset", offset);
+
+        self.get(url.to_string(), Some(params))
+    }
+}
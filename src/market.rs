@@ -0,0 +1,136 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{Error, Spotify};
+
+/// The officially assigned [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) codes,
+/// plus `XK` for Kosovo, which Spotify uses as a market.
+const COUNTRY_CODES: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "XK", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// A market to relink content for: a country code, or the country of the user the token belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Market(Kind);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum Kind {
+    Country([u8; 2]),
+    FromToken,
+}
+
+impl Market {
+    /// The country of the user the access token belongs to, sent as `from_token`.
+    pub const FROM_TOKEN: Market = Market(Kind::FromToken);
+
+    /// Accepts an ISO 3166-1 alpha-2 country code in either case, or `from_token`.
+    pub fn new(code: &str) -> Result<Self, Error> {
+        if code == "from_token" {
+            return Ok(Market::FROM_TOKEN);
+        }
+
+        match Market::from_letters(code) {
+            Some(market) if COUNTRY_CODES.binary_search(&market.as_str()).is_ok() => Ok(market),
+            _ => Err(Error::InvalidArgument(format!(
+                "{:?} is not an ISO 3166-1 alpha-2 country code",
+                code
+            ))),
+        }
+    }
+
+    /// Any two ASCII letters, uppercased, whether or not they are an assigned country code.
+    fn from_letters(code: &str) -> Option<Self> {
+        match code.as_bytes() {
+            [first, second] if first.is_ascii_alphabetic() && second.is_ascii_alphabetic() => {
+                Some(Market(Kind::Country([
+                    first.to_ascii_uppercase(),
+                    second.to_ascii_uppercase(),
+                ])))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn is_from_token(&self) -> bool {
+        self.0 == Kind::FromToken
+    }
+
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            // Only ASCII letters are ever stored.
+            Kind::Country(code) => std::str::from_utf8(code).unwrap(),
+            Kind::FromToken => "from_token",
+        }
+    }
+}
+
+impl fmt::Display for Market {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Market {
+    type Err = Error;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Market::new(code)
+    }
+}
+
+impl Serialize for Market {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Decoding accepts any two letters rather than only assigned codes, so a market Spotify starts serving
+/// before this crate knows about it does not fail the whole response.
+impl<'de> Deserialize<'de> for Market {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        if code == "from_token" {
+            return Ok(Market::FROM_TOKEN);
+        }
+        Market::from_letters(&code).ok_or_else(|| {
+            de::Error::custom(format!("{:?} is not a two-letter country code", code))
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct Markets {
+    markets: Vec<Market>,
+}
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-available-markets)
+    pub fn get_available_markets(&self) -> Result<Vec<Market>, Error> {
+        let markets: Markets = self.get("markets".to_string(), None)?;
+        Ok(markets.markets)
+    }
+}
@@ -1,14 +1,40 @@
-use serde::{Deserialize, Serialize};
+use serde::de::DeserializeOwned;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{Market, ReleaseDate};
+
+/// A date and time in ISO 8601 format as Coordinated Universal Time, such as `2021-05-05T18:55:10Z`.
+///
+/// Decoded as a [`chrono::DateTime`] with the `chrono` feature, otherwise left as the string sent by the Web API.
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+/// A date and time in ISO 8601 format as Coordinated Universal Time, such as `2021-05-05T18:55:10Z`.
+///
+/// Decoded as a [`chrono::DateTime`] with the `chrono` feature, otherwise left as the string sent by the Web API.
+#[cfg(not(feature = "chrono"))]
+pub type Timestamp = String;
+
+/// A Unix timestamp in milliseconds.
+///
+/// Decoded as a [`chrono::DateTime`] with the `chrono` feature, otherwise left as the number sent by the Web API.
+#[cfg(feature = "chrono")]
+pub type UnixTimestamp = chrono::DateTime<chrono::Utc>;
+/// A Unix timestamp in milliseconds.
+///
+/// Decoded as a [`chrono::DateTime`] with the `chrono` feature, otherwise left as the number sent by the Web API.
+#[cfg(not(feature = "chrono"))]
+pub type UnixTimestamp = u64;
 
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-albumobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct AlbumObject {
     /// The type of the album: `album`, `single`, or `compilation`.
-    pub album_type: String,
+    pub album_type: AlbumType,
     /// The artists of the album. Each artist object includes a link in `href` to more detailed information about the artist.
-    pub artists: Vec<ArtistObject>,
+    pub artists: Vec<SimplifiedArtistObject>,
     /// The markets in which the album is available: [ISO 3166-1 alpha-2 country codes.](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) Note that an album is considered available in a market when at least 1 of its tracks is available in that market.
-    pub available_markets: Vec<String>,
+    #[serde(default)]
+    pub available_markets: Vec<Market>,
     /// The copyright statements of the album.
     pub copyrights: Vec<CopyrightObject>,
     /// Known external IDs for the album.
@@ -30,13 +56,14 @@ pub struct AlbumObject {
     /// The popularity of the album. The value will be between 0 and 100, with 100 being the most popular. The popularity is calculated from the popularity of the album’s individual tracks.
     pub popularity: usize,
     /// The date the album was first released, for example “1981-12-15”. Depending on the precision, it might be shown as “1981” or “1981-12”.
-    pub release_date: String,
+    pub release_date: ReleaseDate,
     /// The precision with which release_date value is known: “year” , “month” , or “day”.
-    pub release_date_precision: String,
+    pub release_date_precision: ReleaseDatePrecision,
     /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-albumrestrictionobject) for more details.
-    pub restrictions: AlbumRestrictionObject,
+    #[serde(default)]
+    pub restrictions: Option<AlbumRestrictionObject>,
     /// The tracks of the album.
-    pub tracks: Vec<SimplifiedTrackObject>,
+    pub tracks: PagingObject<SimplifiedTrackObject>,
     /// The object type: “album"
     #[serde(rename = "type")]
     pub _type: String,
@@ -50,7 +77,15 @@ pub struct AlbumRestrictionObject {
     /// * `market` - The content item is not available in the given market.
     /// * `product` - The content item is not available for the user’s subscription type.
     /// * `explicit` - The content item is explicit and the user’s account is set to not play explicit content. Additional reasons may be added in the future. **Note**: If you use this field, make sure that your application safely handles unknown values.
-    pub reason: String,
+    pub reason: RestrictionReason,
+}
+string_enum! {
+    /// The type of an album.
+    pub enum AlbumType {
+        Album => "album",
+        Single => "single",
+        Compilation => "compilation",
+    }
 }
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-artistobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -58,8 +93,10 @@ pub struct ArtistObject {
     /// Known external URLs for this artist.
     pub external_urls: ExternalUrlObject,
     /// Information about the followers of the artist.
+    #[serde(default)]
     pub followers: Option<FollowersObject>,
     /// A list of the genres the artist is associated with. For example: `"Prog Rock"` , `"Post-Grunge"`. (If not yet classified, the array is empty.)
+    #[serde(default)]
     pub genres: Option<Vec<String>>,
     /// A link to the Web API endpoint providing full details of the artist.
     pub href: String,
@@ -78,35 +115,83 @@ pub struct ArtistObject {
     pub uri: String,
 }
 
-/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-audiofeaturesobject)
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-audiobookobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AudiobookObject {
+    /// The author(s) for the audiobook.
+    pub authors: Vec<AuthorObject>,
+    /// A list of the countries in which the audiobook can be played, identified by their [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
+    #[serde(default)]
+    pub available_markets: Vec<Market>,
+    /// The copyright statements of the audiobook.
+    pub copyrights: Vec<CopyrightObject>,
+    /// A description of the audiobook. HTML tags are stripped away from this field, use `html_description` field in case HTML tags are needed.
+    pub description: String,
+    /// A description of the audiobook. This field may contain HTML tags.
+    pub html_description: String,
+    /// The edition of the audiobook.
+    #[serde(default)]
+    pub edition: Option<String>,
+    /// Whether or not the audiobook has explicit content (true = yes it does; false = no it does not OR unknown).
+    pub explicit: bool,
+    /// External URLs for this audiobook.
+    pub external_urls: ExternalUrlObject,
+    /// A link to the Web API endpoint providing full details of the audiobook.
+    pub href: String,
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the audiobook.
+    pub id: String,
+    /// The cover art for the audiobook in various sizes, widest first.
+    pub images: Vec<ImageObject>,
+    /// A list of the languages used in the audiobook, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
+    pub languages: Vec<String>,
+    /// The media type of the audiobook.
+    pub media_type: String,
+    /// The name of the audiobook.
+    pub name: String,
+    /// The narrator(s) for the audiobook.
+    pub narrators: Vec<NarratorObject>,
+    /// The publisher of the audiobook.
+    pub publisher: String,
+    /// The number of chapters in this audiobook.
+    pub total_chapters: usize,
+    /// The object type: “audiobook”.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the audiobook.
+    pub uri: String,
+    /// The chapters of the audiobook.
+    pub chapters: PagingObject<SimplifiedChapterObject>,
+}
+
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-audiofeaturesobject)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct AudioFeaturesObject {
     /// A confidence measure from 0.0 to 1.0 of whether the track is acoustic. 1.0 represents high confidence the track is acoustic.
-    pub acousticness: usize,
+    pub acousticness: f64,
     /// An HTTP URL to access the full audio analysis of this track. An access token is required to access this data.
     pub analysis_url: String,
     /// Danceability describes how suitable a track is for dancing based on a combination of musical elements including tempo, rhythm stability, beat strength, and overall regularity. A value of 0.0 is least danceable and 1.0 is most danceable.
-    pub danceability: usize,
+    pub danceability: f64,
     /// The duration of the track in milliseconds.
     pub duration_ms: usize,
     /// Energy is a measure from 0.0 to 1.0 and represents a perceptual measure of intensity and activity. Typically, energetic tracks feel fast, loud, and noisy. For example, death metal has high energy, while a Bach prelude scores low on the scale. Perceptual features contributing to this attribute include dynamic range, perceived loudness, timbre, onset rate, and general entropy.
-    pub energy: usize,
+    pub energy: f64,
     /// The Spotify ID for the track.
     pub id: String,
     /// Predicts whether a track contains no vocals. “Ooh” and “aah” sounds are treated as instrumental in this context. Rap or spoken word tracks are clearly “vocal”. The closer the instrumentalness value is to 1.0, the greater likelihood the track contains no vocal content. Values above 0.5 are intended to represent instrumental tracks, but confidence is higher as the value approaches 1.0.
-    pub instrumentalness: usize,
-    /// The key the track is in. Integers map to pitches using standard [Pitch Class notation](https://en.wikipedia.org/wiki/Pitch_class). E.g. 0 = C, 1 = C♯/D♭, 2 = D, and so on.
-    pub key: usize,
+    pub instrumentalness: f64,
+    /// The key the track is in. Integers map to pitches using standard [Pitch Class notation](https://en.wikipedia.org/wiki/Pitch_class). E.g. 0 = C, 1 = C♯/D♭, 2 = D, and so on. If no key was detected, the value is -1.
+    pub key: i32,
     /// Detects the presence of an audience in the recording. Higher liveness values represent an increased probability that the track was performed live. A value above 0.8 provides strong likelihood that the track is live.
-    pub liveness: usize,
+    pub liveness: f64,
     /// The overall loudness of a track in decibels (dB). Loudness values are averaged across the entire track and are useful for comparing relative loudness of tracks. Loudness is the quality of a sound that is the primary psychological correlate of physical strength (amplitude). Values typical range between -60 and 0 db.
-    pub loudness: usize,
+    pub loudness: f64,
     /// Mode indicates the modality (major or minor) of a track, the type of scale from which its melodic content is derived. Major is represented by 1 and minor is 0.
-    pub mode: usize,
+    pub mode: i32,
     /// Speechiness detects the presence of spoken words in a track. The more exclusively speech-like the recording (e.g. talk show, audio book, poetry), the closer to 1.0 the attribute value. Values above 0.66 describe tracks that are probably made entirely of spoken words. Values between 0.33 and 0.66 describe tracks that may contain both music and speech, either in sections or layered, including such cases as rap music. Values below 0.33 most likely represent music and other non-speech-like tracks.
-    pub speechiness: usize,
+    pub speechiness: f64,
     /// The overall estimated tempo of a track in beats per minute (BPM). In musical terminology, tempo is the speed or pace of a given piece and derives directly from the average beat duration.
-    pub tempo: usize,
+    pub tempo: f64,
     /// An estimated overall time signature of a track. The time signature (meter) is a notational convention to specify how many beats are in each bar (or measure).
     pub time_signature: usize,
     /// A link to the Web API endpoint providing full details of the track.
@@ -117,7 +202,14 @@ pub struct AudioFeaturesObject {
     /// The Spotify URI for the track.
     pub uri: String,
     /// A measure from 0.0 to 1.0 describing the musical positiveness conveyed by a track. Tracks with high valence sound more positive (e.g. happy, cheerful, euphoric), while tracks with low valence sound more negative (e.g. sad, depressed, angry).
-    pub valence: usize,
+    pub valence: f64,
+}
+
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-authorobject)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AuthorObject {
+    /// The name of the author.
+    pub name: String,
 }
 
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-categoryobject)
@@ -133,6 +225,69 @@ pub struct CategoryObject {
     pub name: String,
 }
 
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-chapterobject)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChapterObject {
+    /// A URL to a 30 second preview (MP3 format) of the chapter. `null` if not available.
+    #[serde(default)]
+    pub audio_preview_url: Option<String>,
+    /// A list of the countries in which the chapter can be played, identified by their [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
+    #[serde(default)]
+    pub available_markets: Vec<Market>,
+    /// The number of the chapter.
+    pub chapter_number: usize,
+    /// A description of the chapter. HTML tags are stripped away from this field, use `html_description` field in case HTML tags are needed.
+    pub description: String,
+    /// A description of the chapter. This field may contain HTML tags.
+    pub html_description: String,
+    /// The chapter length in milliseconds.
+    pub duration_ms: usize,
+    /// Whether or not the chapter has explicit content (true = yes it does; false = no it does not OR unknown).
+    pub explicit: bool,
+    /// External URLs for this chapter.
+    pub external_urls: ExternalUrlObject,
+    /// A link to the Web API endpoint providing full details of the chapter.
+    pub href: String,
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the chapter.
+    pub id: String,
+    /// The cover art for the chapter in various sizes, widest first.
+    pub images: Vec<ImageObject>,
+    /// True if the chapter is playable in the given market. Otherwise false.
+    #[serde(default)]
+    pub is_playable: Option<bool>,
+    /// A list of the languages used in the chapter, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
+    pub languages: Vec<String>,
+    /// The name of the chapter.
+    pub name: String,
+    /// The date the chapter was first released, for example `"1981-12-15"`. Depending on the precision, it might be shown as `"1981"` or `"1981-12"`.
+    pub release_date: ReleaseDate,
+    /// The precision with which `release_date` value is known: `"year"`, `"month"`, or `"day"`.
+    pub release_date_precision: ReleaseDatePrecision,
+    /// The user’s most recent position in the chapter. Set if the supplied access token is a user token and has the scope `user-read-playback-position`.
+    #[serde(default)]
+    pub resume_point: Option<ResumePointObject>,
+    /// The object type: “chapter”.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the chapter.
+    pub uri: String,
+    /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-chapterrestrictionobject) for more details.
+    #[serde(default)]
+    pub restrictions: Option<ChapterRestrictionObject>,
+    /// The audiobook for which the chapter belongs.
+    pub audiobook: SimplifiedAudiobookObject,
+}
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-chapterrestrictionobject)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ChapterRestrictionObject {
+    /// The reason for the restriction. Supported values:
+    /// * `market` - The content item is not available in the given market.
+    /// * `product` - The content item is not available for the user’s subscription type.
+    /// * `explicit` - The content item is explicit and the user’s account is set to not play explicit content.
+    /// * `payment_required` - Payment is required to play the content item. Additional reasons may be added in the future. **Note**: If you use this field, make sure that your application safely handles unknown values.
+    pub reason: RestrictionReason,
+}
+
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-contextobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ContextObject {
@@ -154,7 +309,17 @@ pub struct CopyrightObject {
     pub text: String,
     /// The type of copyright: `C` = the copyright, `P` = the sound recording (performance) copyright.
     #[serde(rename = "type")]
-    pub _type: String,
+    pub _type: CopyrightType,
+}
+
+string_enum! {
+    /// The type of a copyright.
+    pub enum CopyrightType {
+        /// The copyright.
+        Copyright => "C",
+        /// The sound recording (performance) copyright.
+        Performance => "P",
+    }
 }
 
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-currentlyplayingcontextobject)
@@ -163,69 +328,95 @@ pub struct CurrentlyPlayingContextObject {
     /// Allows to update the user interface based on which playback actions are available within the current context.
     pub actions: DisallowsObject,
     /// A Context Object. Can be `null`.
-    pub context: ContextObject,
+    #[serde(default)]
+    pub context: Option<ContextObject>,
     /// The object type of the currently playing item. Can be one of `track`, `episode`, `ad` or `unknown`.
-    pub currently_playing_type: String,
+    pub currently_playing_type: CurrentlyPlayingType,
     /// The device that is currently active.
     pub device: DeviceObject,
     /// If something is currently playing, return `true`.
     pub is_playing: bool,
     /// The currently playing track or episode. Can be `null`.
-    pub item: PlaylistItemType<TrackObject, EpisodeObject>,
+    #[serde(default)]
+    pub item: Option<PlaylistItemType<TrackObject, EpisodeObject>>,
     /// Progress into the currently playing track or episode. Can be `null`.
-    pub progress_ms: usize,
+    #[serde(default)]
+    pub progress_ms: Option<usize>,
     /// off, track, context
-    pub repeat_state: String,
+    pub repeat_state: RepeatState,
     /// If shuffle is on or off.
-    pub shuffle_state: String,
+    pub shuffle_state: bool,
     /// Unix Millisecond Timestamp when data was fetched.
-    pub timestamp: usize,
+    #[cfg_attr(feature = "chrono", serde(with = "chrono::serde::ts_milliseconds"))]
+    pub timestamp: UnixTimestamp,
 }
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-currentlyplayingobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CurrentlyPlayingObject {
     /// A Context Object. Can be `null`.
-    pub context: ContextObject,
+    #[serde(default)]
+    pub context: Option<ContextObject>,
     /// The object type of the currently playing item. Can be one of `track`, `episode`, `ad` or `unknown`.
-    pub currently_playing_type: String,
+    pub currently_playing_type: CurrentlyPlayingType,
     /// If something is currently playing, return `true`.
     pub is_playing: bool,
     /// The currently playing track or episode. Can be `null`.
-    pub item: PlaylistItemType<TrackObject, EpisodeObject>,
+    #[serde(default)]
+    pub item: Option<PlaylistItemType<TrackObject, EpisodeObject>>,
     /// Progress into the currently playing track or episode. Can be `null`.
-    pub progress_ms: usize,
+    #[serde(default)]
+    pub progress_ms: Option<usize>,
     /// If shuffle is on or off.
-    pub shuffle_state: String,
+    #[serde(default)]
+    pub shuffle_state: Option<bool>,
     /// Unix Millisecond Timestamp when data was fetched.
-    pub timestamp: usize,
+    #[cfg_attr(feature = "chrono", serde(with = "chrono::serde::ts_milliseconds"))]
+    pub timestamp: UnixTimestamp,
+}
+string_enum! {
+    /// The type of the currently playing item.
+    pub enum CurrentlyPlayingType {
+        Track => "track",
+        Episode => "episode",
+        Ad => "ad",
+        Unknown => "unknown",
+    }
 }
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-cursorobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CursorObject {
     /// The cursor to use as key to find the next page of items.
-    pub after: String,
+    #[serde(default)]
+    pub after: Option<String>,
+    /// The cursor to use as key to find the previous page of items.
+    #[serde(default)]
+    pub before: Option<String>,
 }
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-cursorpagingobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-pub struct CursorPagingObject {
+pub struct CursorPagingObject<T> {
     /// The cursors used to find the next set of items.
-    pub cursors: CursorObject,
+    #[serde(default)]
+    pub cursors: Option<CursorObject>,
     /// A link to the Web API endpoint returning the full result of the request.
     pub href: String,
     /// The requested data.
-    //items: Vec<Object>
+    pub items: Vec<T>,
     /// The maximum number of items in the response (as set in the query or by default)
     pub limit: usize,
     /// URL to the next page of items. (`null` if none)
-    pub next: String,
-    /// The total number of items available to return.
-    pub total: usize,
+    #[serde(default)]
+    pub next: Option<String>,
+    /// The total number of items available to return. Not returned for recently played tracks.
+    #[serde(default)]
+    pub total: Option<usize>,
 }
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-deviceobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct DeviceObject {
     /// The device ID. This may be `null`.
-    pub id: String,
+    #[serde(default)]
+    pub id: Option<String>,
     /// If this device is the currently active device.
     pub is_active: bool,
     /// If this device is currently in a private session.
@@ -236,9 +427,28 @@ pub struct DeviceObject {
     pub name: String,
     /// Device type, such as “computer”, “smartphone” or “speaker”.
     #[serde(rename = "type")]
-    pub _type: String,
+    pub _type: DeviceType,
     /// The current volume in percent. This may be null.
-    pub volume_percent: usize,
+    #[serde(default)]
+    pub volume_percent: Option<usize>,
+}
+string_enum! {
+    /// The type of a device.
+    pub enum DeviceType {
+        Computer => "Computer",
+        Tablet => "Tablet",
+        Smartphone => "Smartphone",
+        Speaker => "Speaker",
+        Tv => "TV",
+        Avr => "AVR",
+        Stb => "STB",
+        AudioDongle => "AudioDongle",
+        GameConsole => "GameConsole",
+        CastVideo => "CastVideo",
+        CastAudio => "CastAudio",
+        Automobile => "Automobile",
+        Unknown => "Unknown",
+    }
 }
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-devicesobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -250,31 +460,42 @@ pub struct DevicesObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct DisallowsObject {
     /// Interrupting playback. Optional field.
+    #[serde(default)]
     pub interrupting_playback: Option<bool>,
     /// Pausing. Optional field.
+    #[serde(default)]
     pub pausing: Option<bool>,
     /// Resuming. Optional field.
+    #[serde(default)]
     pub resuming: Option<bool>,
     /// Seeking playback location. Optional field.
+    #[serde(default)]
     pub seeking: Option<bool>,
     /// Skipping to the next context. Optional field.
+    #[serde(default)]
     pub skipping_next: Option<bool>,
     /// Skipping to the previous context. Optional field.
+    #[serde(default)]
     pub skipping_prev: Option<bool>,
     /// Toggling repeat context flag. Optional field.
+    #[serde(default)]
     pub toggling_repeat_context: Option<bool>,
     /// Toggling repeat track flag. Optional field.
+    #[serde(default)]
     pub toggling_repeat_track: Option<bool>,
     /// Toggling shuffle flag. Optional field.
+    #[serde(default)]
     pub toggling_shuffle: Option<bool>,
     /// Transfering playback between devices. Optional field.
+    #[serde(default)]
     pub transferring_playback: Option<bool>,
 }
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-episodeobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct EpisodeObject {
     /// A URL to a 30 second preview (MP3 format) of the episode. `null` if not available.
-    pub audio_preview_url: String,
+    #[serde(default)]
+    pub audio_preview_url: Option<String>,
     /// A description of the episode. HTML tags are stripped away from this field, use `html_description` field in case HTML tags are needed.
     pub description: String,
     /// The episode length in milliseconds.
@@ -294,21 +515,25 @@ pub struct EpisodeObject {
     /// True if the episode is hosted outside of Spotify’s CDN.
     pub is_externally_hosted: bool,
     /// True if the episode is playable in the given market. Otherwise false.
-    pub is_playable: bool,
+    #[serde(default)]
+    pub is_playable: Option<bool>,
     /// **Note: This field is deprecated and might be removed in the future. Please use the languages field instead.** The language used in the episode, identified by a [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
-    pub language: String,
+    #[serde(default)]
+    pub language: Option<String>,
     /// A list of the languages used in the episode, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
     pub languages: Vec<String>,
     /// The name of the episode.
     pub name: String,
     /// The date the episode was first released, for example `"1981-12-15"`. Depending on the precision, it might be shown as `"1981"` or `"1981-12"`.
-    pub release_date: String,
+    pub release_date: ReleaseDate,
     /// The precision with which `release_date` value is known: `"year"`, `"month"`, or `"day"`.
-    pub release_date_precision: String,
+    pub release_date_precision: ReleaseDatePrecision,
     /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-episoderestrictionobject) for more details.
-    pub restrictions: EpisodeRestrictionObject,
+    #[serde(default)]
+    pub restrictions: Option<EpisodeRestrictionObject>,
     /// The user’s most recent position in the episode. Set if the supplied access token is a user token and has the scope `user-read-playback-position`.
-    pub resume_point: ResumePointObject,
+    #[serde(default)]
+    pub resume_point: Option<ResumePointObject>,
     /// The show on which the episode belongs.
     pub show: SimplifiedShowObject,
     /// The object type: “episode”.
@@ -324,7 +549,7 @@ pub struct EpisodeRestrictionObject {
     /// * `market` - The content item is not available in the given market.
     /// * `product` - The content item is not available for the user’s subscription type.
     /// * `explicit` - The content item is explicit and the user’s account is set to not play explicit content. Additional reasons may be added in the future. **Note**: If you use this field, make sure that your application safely handles unknown values.
-    pub reason: String,
+    pub reason: RestrictionReason,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -351,11 +576,14 @@ pub struct ExplicitContentSettingsObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ExternalIdObject {
     /// [International Article Number](https://en.wikipedia.org/wiki/International_Article_Number)
-    pub ean: String,
+    #[serde(default)]
+    pub ean: Option<String>,
     /// [International Standard Recording Code](https://en.wikipedia.org/wiki/International_Standard_Recording_Code)
-    pub isrc: String,
+    #[serde(default)]
+    pub isrc: Option<String>,
     /// [Universal Product Code](https://en.wikipedia.org/wiki/Universal_Product_Code)
-    pub upc: String,
+    #[serde(default)]
+    pub upc: Option<String>,
 }
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-externalurlobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -367,7 +595,8 @@ pub struct ExternalUrlObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct FollowersObject {
     /// A link to the Web API endpoint providing full details of the followers; `null` if not available. Please note that this will always be set to null, as the Web API does not support it at the moment.
-    pub href: String,
+    #[serde(default)]
+    pub href: Option<String>,
     /// The total number of followers.
     pub total: usize,
 }
@@ -375,10 +604,12 @@ pub struct FollowersObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ImageObject {
     /// The image height in pixels. If unknown: `null` or not returned.
+    #[serde(default)]
     pub height: Option<u32>,
     /// The source URL of the image.
     pub url: String,
     /// The image width in pixels. If unknown: `null` or not returned.
+    #[serde(default)]
     pub width: Option<u32>,
 }
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-linkedtrackobject)
@@ -396,21 +627,29 @@ pub struct LinkedTrackObject {
     /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the track.
     pub uri: String,
 }
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-narratorobject)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct NarratorObject {
+    /// The name of the narrator.
+    pub name: String,
+}
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-pagingobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-pub struct PagingObject {
+pub struct PagingObject<T> {
     /// A link to the Web API endpoint returning the full result of the request
     pub href: String,
     /// The requested data.
-    //items: Vec<Object>,
+    pub items: Vec<T>,
     /// The maximum number of items in the response (as set in the query or by default).
     pub limit: usize,
     /// URL to the next page of items. (`null` if none)
-    pub next: String,
+    #[serde(default)]
+    pub next: Option<String>,
     /// The offset of the items returned (as set in the query or by default)
     pub offset: usize,
     /// URL to the previous page of items. (`null` if none)
-    pub previous: String,
+    #[serde(default)]
+    pub previous: Option<String>,
     /// The total number of items available to return.
     pub total: usize,
 }
@@ -418,11 +657,12 @@ pub struct PagingObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PlayHistoryObject {
     /// The context the track was played from.
-    pub context: ContextObject,
+    #[serde(default)]
+    pub context: Option<ContextObject>,
     /// The date and time the track was played.
-    //played_at: Timestamp,
+    pub played_at: Timestamp,
     /// The track the user listened to.
-    pub track: SimplifiedTrackObject,
+    pub track: TrackObject,
 }
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-playererrorobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -456,7 +696,8 @@ pub struct PlaylistObject {
     /// `true` if the owner allows other users to modify the playlist.
     pub collaborative: bool,
     /// The playlist description. Only returned for modified, verified playlists, otherwise `null`.
-    pub description: String,
+    #[serde(default)]
+    pub description: Option<String>,
     /// Known external URLs for this playlist.
     pub external_urls: ExternalUrlObject,
     /// Information about the followers of the playlist.
@@ -472,11 +713,12 @@ pub struct PlaylistObject {
     /// The user who owns the playlist
     pub owner: PublicUserObject,
     /// The playlist’s public/private status: `true` the playlist is public, `false` the playlist is private, `null` the playlist status is not relevant. For more about public/private status, see [Working with Playlists](https://developer.spotify.com/documentation/general/guides/working-with-playlists/)
-    pub public: bool,
+    #[serde(default)]
+    pub public: Option<bool>,
     /// The version identifier for the current playlist. Can be supplied in other requests to target a specific playlist version
     pub snapshot_id: String,
     /// Information about the tracks of the playlist. Note, a track object may be `null`. This can happen if a track is no longer available.
-    pub tracks: Vec<PlaylistTrackObject>,
+    pub tracks: PagingObject<PlaylistTrackObject>,
     /// The object type: “playlist”
     #[serde(rename = "type")]
     pub _type: String,
@@ -487,19 +729,73 @@ pub struct PlaylistObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PlaylistTrackObject {
     /// The date and time the track or episode was added. Note that some very old playlists may return `null` in this field.
-    //added_at: Timestamp,
+    #[serde(default)]
+    pub added_at: Option<Timestamp>,
     /// The Spotify user who added the track or episode. Note that some very old playlists may return `null` in this field.
-    pub added_by: PublicUserObject,
+    #[serde(default)]
+    pub added_by: Option<PublicUserObject>,
     /// Whether this track or episode is a [local file](https://developer.spotify.com/documentation/general/guides/local-files-spotify-playlists/) or not.
     pub is_local: bool,
     /// Information about the track or episode.
-    pub track: PlaylistItemType<TrackObject, EpisodeObject>,
+    #[serde(default)]
+    pub track: Option<PlaylistItemType<TrackObject, EpisodeObject>>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
-pub enum PlaylistItemType<T, E> {
+/// A playable item, told apart by its `type` field.
+///
+/// Ads, local files and items of a type added after this crate are kept as [`Unknown`](PlaylistItemType::Unknown)
+/// rather than failing to decode.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlaylistItemType<T, E, C = ChapterObject> {
     Track(T),
     Episode(E),
+    Chapter(C),
+    Unknown(serde_json::Value),
+}
+
+impl<T, E, C> Serialize for PlaylistItemType<T, E, C>
+where
+    T: Serialize,
+    E: Serialize,
+    C: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            PlaylistItemType::Track(track) => track.serialize(serializer),
+            PlaylistItemType::Episode(episode) => episode.serialize(serializer),
+            PlaylistItemType::Chapter(chapter) => chapter.serialize(serializer),
+            PlaylistItemType::Unknown(value) => value.serialize(serializer),
+        }
+    }
+}
+
+impl<'de, T, E, C> Deserialize<'de> for PlaylistItemType<T, E, C>
+where
+    T: DeserializeOwned,
+    E: DeserializeOwned,
+    C: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        // Local files are typed as tracks, but lack an ID, an album and most other fields.
+        if value.get("is_local") == Some(&serde_json::Value::Bool(true)) {
+            return Ok(PlaylistItemType::Unknown(value));
+        }
+
+        let item = match value.get("type").and_then(|_type| _type.as_str()) {
+            Some("track") => T::deserialize(value).map(PlaylistItemType::Track),
+            Some("episode") => E::deserialize(value).map(PlaylistItemType::Episode),
+            Some("chapter") => C::deserialize(value).map(PlaylistItemType::Chapter),
+            _ => return Ok(PlaylistItemType::Unknown(value)),
+        };
+        item.map_err(de::Error::custom)
+    }
 }
 
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-playlisttracksrefobject)
@@ -514,13 +810,17 @@ pub struct PlaylistTracksRefObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PrivateUserObject {
     /// The country of the user, as set in the user’s account profile. An [ISO 3166-1 alpha-2 country code](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2). This field is only available when the current user has granted access to the [user-read-private](https://developer.spotify.com/documentation/general/guides/authorization-guide/#list-of-scopes) scope.
-    pub country: String,
+    #[serde(default)]
+    pub country: Option<Market>,
     /// The name displayed on the user’s profile. `null` if not available.
-    pub display_name: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
     /// The user’s email address, as entered by the user when creating their account. **_Important!_** This email address is unverified; there is no proof that it actually belongs to the user. This field is only available when the current user has granted access to the [user-read-email](https://developer.spotify.com/documentation/general/guides/authorization-guide/#list-of-scopes) scope.
-    pub email: String,
+    #[serde(default)]
+    pub email: Option<String>,
     /// The user’s explicit content settings. This field is only available when the current user has granted access to the [user-read-private](https://developer.spotify.com/documentation/general/guides/authorization-guide/#list-of-scopes) scope.
-    pub explicit_content: ExplicitContentSettingsObject,
+    #[serde(default)]
+    pub explicit_content: Option<ExplicitContentSettingsObject>,
     /// Known external URLs for this user.
     pub external_urls: ExternalUrlObject,
     /// Information about the followers of the user.
@@ -532,27 +832,40 @@ pub struct PrivateUserObject {
     /// The user’s profile image.
     pub images: Vec<ImageObject>,
     /// The user’s Spotify subscription level: “premium”, “free”, etc. (The subscription level “open” can be considered the same as “free”.) This field is only available when the current user has granted access to the [user-read-private](https://developer.spotify.com/documentation/general/guides/authorization-guide/#list-of-scopes) scope.
-    pub product: String,
+    #[serde(default)]
+    pub product: Option<Product>,
     /// The object type: “user”
     #[serde(rename = "type")]
     pub _type: String,
     /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the user.
     pub uri: String,
 }
+string_enum! {
+    /// The Spotify subscription level of a user.
+    pub enum Product {
+        Premium => "premium",
+        Free => "free",
+        /// Can be considered the same as `Free`.
+        Open => "open",
+    }
+}
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-publicuserobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PublicUserObject {
     /// The name displayed on the user’s profile. `null` if not available.
-    pub display_name: String,
+    #[serde(default)]
+    pub display_name: Option<String>,
     /// Known public external URLs for this user.
     pub external_urls: ExternalUrlObject,
     /// Information about the followers of this user.
-    pub followers: FollowersObject,
+    #[serde(default)]
+    pub followers: Option<FollowersObject>,
     /// A link to the Web API endpoint for this user.
     pub href: String,
     /// The [Spotify user ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for this user.
     pub id: String,
     /// The user’s profile image.
+    #[serde(default)]
     pub images: Vec<ImageObject>,
     /// The object type: “user”
     #[serde(rename = "type")]
@@ -560,6 +873,15 @@ pub struct PublicUserObject {
     /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for this user.
     pub uri: String,
 }
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-queue)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct QueueObject {
+    /// The currently playing track or episode. Can be `null`.
+    #[serde(default)]
+    pub currently_playing: Option<PlaylistItemType<TrackObject, EpisodeObject>>,
+    /// The tracks or episodes in the queue.
+    pub queue: Vec<PlaylistItemType<TrackObject, EpisodeObject>>,
+}
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-recommendationseedobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RecommendationSeedObject {
@@ -570,7 +892,8 @@ pub struct RecommendationSeedObject {
     #[serde(rename = "afterRelinkingSize")]
     pub after_relinking_size: usize,
     /// A link to the full track or artist data for this seed. For tracks this will be a link to a [Track Object](https://developer.spotify.com/documentation/web-api/reference/#object-trackobject). For artists a link to [an Artist Object](https://developer.spotify.com/documentation/web-api/reference/#object-artistobject). For genre seeds, this value will be `null`.
-    pub href: String,
+    #[serde(default)]
+    pub href: Option<String>,
     /// The id used to select this seed. This will be the same as the string used in the `seed_artists`, `seed_tracks` or `seed_genres` parameter.
     pub id: String,
     /// The number of recommended tracks available for this seed.
@@ -588,6 +911,38 @@ pub struct RecommendationsObject {
     /// An array of [track object (simplified)](https://developer.spotify.com/documentation/web-api/reference/#object-simplifiedtrackobject) ordered according to the parameters supplied.
     pub tracks: Vec<SimplifiedTrackObject>,
 }
+string_enum! {
+    /// The precision with which a release date is known.
+    pub enum ReleaseDatePrecision {
+        Year => "year",
+        Month => "month",
+        Day => "day",
+    }
+}
+string_enum! {
+    /// The repeat mode of the player.
+    pub enum RepeatState {
+        /// Repeats the current track.
+        Track => "track",
+        /// Repeats the current context.
+        Context => "context",
+        /// Turns repeat off.
+        Off => "off",
+    }
+}
+string_enum! {
+    /// The reason content is restricted.
+    pub enum RestrictionReason {
+        /// The content is not available in the given market.
+        Market => "market",
+        /// The content is not available for the user’s subscription type.
+        Product => "product",
+        /// The content is explicit and the user’s account is set to not play explicit content.
+        Explicit => "explicit",
+        /// Payment is required to play the content.
+        PaymentRequired => "payment_required",
+    }
+}
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-resumepointobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ResumePointObject {
@@ -600,7 +955,7 @@ pub struct ResumePointObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SavedAlbumObject {
     /// The date and time the album was saved Timestamps are returned in ISO 8601 format as Coordinated Universal Time (UTC) with a zero offset: YYYY-MM-DDTHH:MM:SSZ. If the time is imprecise (for example, the date/time of an album release), an additional field indicates the precision; see for example, release_date in an album object.
-    pub added_at: String,
+    pub added_at: Timestamp,
     /// Information about the album.
     pub album: AlbumObject,
 }
@@ -608,7 +963,7 @@ pub struct SavedAlbumObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SavedEpisodeObject {
     /// The date and time the episode was saved. Timestamps are returned in ISO 8601 format as Coordinated Universal Time (UTC) with a zero offset: YYYY-MM-DDTHH:MM:SSZ.
-    pub added_at: String,
+    pub added_at: Timestamp,
     /// Information about the episode.
     pub episode: EpisodeObject,
 }
@@ -616,7 +971,7 @@ pub struct SavedEpisodeObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SavedShowObject {
     /// The date and time the show was saved. Timestamps are returned in ISO 8601 format as Coordinated Universal Time (UTC) with a zero offset: YYYY-MM-DDTHH:MM:SSZ. If the time is imprecise (for example, the date/time of an album release), an additional field indicates the precision; see for example, release_date in an album object.
-    pub added_at: String,
+    pub added_at: Timestamp,
     /// Information about the show.
     pub show: SimplifiedShowObject,
 }
@@ -624,7 +979,7 @@ pub struct SavedShowObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SavedTrackObject {
     /// The date and time the track was saved. Timestamps are returned in ISO 8601 format as Coordinated Universal Time (UTC) with a zero offset: YYYY-MM-DDTHH:MM:SSZ. If the time is imprecise (for example, the date/time of an album release), an additional field indicates the precision; see for example, release_date in an album object.
-    pub added_at: String,
+    pub added_at: Timestamp,
     /// Information about the track.
     pub track: TrackObject,
 }
@@ -632,13 +987,14 @@ pub struct SavedTrackObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ShowObject {
     /// A list of the countries in which the show can be played, identified by their [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
-    pub available_markets: Vec<String>,
+    #[serde(default)]
+    pub available_markets: Vec<Market>,
     /// The copyright statements of the show.
     pub copyrights: Vec<CopyrightObject>,
     /// A description of the show.
     pub description: String,
     /// A list of the show’s episodes.
-    pub episodes: Vec<SimplifiedEpisodeObject>,
+    pub episodes: PagingObject<SimplifiedEpisodeObject>,
     /// Whether or not the show has explicit content (true = yes it does; false = no it does not OR unknown).
     pub explicit: bool,
     /// External URLs for this show.
@@ -650,7 +1006,8 @@ pub struct ShowObject {
     /// The cover art for the show in various sizes, widest first.
     pub images: Vec<ImageObject>,
     /// True if all of the show’s episodes are hosted outside of Spotify’s CDN. This field might be `null` in some cases.
-    pub is_externally_hosted: bool,
+    #[serde(default)]
+    pub is_externally_hosted: Option<bool>,
     /// A list of the languages used in the show, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
     pub languages: Vec<String>,
     /// The media type of the show.
@@ -669,11 +1026,12 @@ pub struct ShowObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SimplifiedAlbumObject {
     /// The type of the album: `album`, `single`, or `compilation`.
-    pub album_type: String,
+    pub album_type: AlbumType,
     /// The artists of the album. Each artist object includes a link in `href` to more detailed information about the artist.
-    pub artists: Vec<ArtistObject>,
+    pub artists: Vec<SimplifiedArtistObject>,
     /// The markets in which the album is available: [ISO 3166-1 alpha-2 country codes.](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) Note that an album is considered available in a market when at least 1 of its tracks is available in that market.
-    pub available_markets: Vec<String>,
+    #[serde(default)]
+    pub available_markets: Vec<Market>,
     /// Known external URLs for this album.
     pub external_urls: ExternalUrlObject,
     /// A link to the Web API endpoint providing full details of the album.
@@ -685,11 +1043,12 @@ pub struct SimplifiedAlbumObject {
     /// The name of the album. In case of an album takedown, the value may be an empty string.
     pub name: String,
     /// The date the album was first released, for example “1981-12-15”. Depending on the precision, it might be shown as “1981” or “1981-12”.
-    pub release_date: String,
+    pub release_date: ReleaseDate,
     /// The precision with which release_date value is known: “year” , “month” , or “day”.
-    pub release_date_precision: String,
+    pub release_date_precision: ReleaseDatePrecision,
     /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-albumrestrictionobject) for more details.
-    pub restrictions: AlbumRestrictionObject,
+    #[serde(default)]
+    pub restrictions: Option<AlbumRestrictionObject>,
     /// The object type: “album"
     #[serde(rename = "type")]
     pub _type: String,
@@ -713,11 +1072,107 @@ pub struct SimplifiedArtistObject {
     /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the artist.
     pub uri: String,
 }
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-simplifiedaudiobookobject)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SimplifiedAudiobookObject {
+    /// The author(s) for the audiobook.
+    pub authors: Vec<AuthorObject>,
+    /// A list of the countries in which the audiobook can be played, identified by their [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
+    #[serde(default)]
+    pub available_markets: Vec<Market>,
+    /// The copyright statements of the audiobook.
+    pub copyrights: Vec<CopyrightObject>,
+    /// A description of the audiobook. HTML tags are stripped away from this field, use `html_description` field in case HTML tags are needed.
+    pub description: String,
+    /// A description of the audiobook. This field may contain HTML tags.
+    pub html_description: String,
+    /// The edition of the audiobook.
+    #[serde(default)]
+    pub edition: Option<String>,
+    /// Whether or not the audiobook has explicit content (true = yes it does; false = no it does not OR unknown).
+    pub explicit: bool,
+    /// External URLs for this audiobook.
+    pub external_urls: ExternalUrlObject,
+    /// A link to the Web API endpoint providing full details of the audiobook.
+    pub href: String,
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the audiobook.
+    pub id: String,
+    /// The cover art for the audiobook in various sizes, widest first.
+    pub images: Vec<ImageObject>,
+    /// A list of the languages used in the audiobook, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
+    pub languages: Vec<String>,
+    /// The media type of the audiobook.
+    pub media_type: String,
+    /// The name of the audiobook.
+    pub name: String,
+    /// The narrator(s) for the audiobook.
+    pub narrators: Vec<NarratorObject>,
+    /// The publisher of the audiobook.
+    pub publisher: String,
+    /// The number of chapters in this audiobook.
+    pub total_chapters: usize,
+    /// The object type: “audiobook”.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the audiobook.
+    pub uri: String,
+}
+/// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-simplifiedchapterobject)
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SimplifiedChapterObject {
+    /// A URL to a 30 second preview (MP3 format) of the chapter. `null` if not available.
+    #[serde(default)]
+    pub audio_preview_url: Option<String>,
+    /// A list of the countries in which the chapter can be played, identified by their [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
+    #[serde(default)]
+    pub available_markets: Vec<Market>,
+    /// The number of the chapter.
+    pub chapter_number: usize,
+    /// A description of the chapter. HTML tags are stripped away from this field, use `html_description` field in case HTML tags are needed.
+    pub description: String,
+    /// A description of the chapter. This field may contain HTML tags.
+    pub html_description: String,
+    /// The chapter length in milliseconds.
+    pub duration_ms: usize,
+    /// Whether or not the chapter has explicit content (true = yes it does; false = no it does not OR unknown).
+    pub explicit: bool,
+    /// External URLs for this chapter.
+    pub external_urls: ExternalUrlObject,
+    /// A link to the Web API endpoint providing full details of the chapter.
+    pub href: String,
+    /// The [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the chapter.
+    pub id: String,
+    /// The cover art for the chapter in various sizes, widest first.
+    pub images: Vec<ImageObject>,
+    /// True if the chapter is playable in the given market. Otherwise false.
+    #[serde(default)]
+    pub is_playable: Option<bool>,
+    /// A list of the languages used in the chapter, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
+    pub languages: Vec<String>,
+    /// The name of the chapter.
+    pub name: String,
+    /// The date the chapter was first released, for example `"1981-12-15"`. Depending on the precision, it might be shown as `"1981"` or `"1981-12"`.
+    pub release_date: ReleaseDate,
+    /// The precision with which `release_date` value is known: `"year"`, `"month"`, or `"day"`.
+    pub release_date_precision: ReleaseDatePrecision,
+    /// The user’s most recent position in the chapter. Set if the supplied access token is a user token and has the scope `user-read-playback-position`.
+    #[serde(default)]
+    pub resume_point: Option<ResumePointObject>,
+    /// The object type: “chapter”.
+    #[serde(rename = "type")]
+    pub _type: String,
+    /// The [Spotify URI](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) for the chapter.
+    pub uri: String,
+    /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-chapterrestrictionobject) for more details.
+    #[serde(default)]
+    pub restrictions: Option<ChapterRestrictionObject>,
+}
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-simplifiedepisodeobject)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SimplifiedEpisodeObject {
     /// A URL to a 30 second preview (MP3 format) of the episode. `null` if not available.
-    pub audio_preview_url: String,
+    #[serde(default)]
+    pub audio_preview_url: Option<String>,
     /// A description of the episode. HTML tags are stripped away from this field, use `html_description` field in case HTML tags are needed.
     pub description: String,
     /// The episode length in milliseconds.
@@ -737,21 +1192,25 @@ pub struct SimplifiedEpisodeObject {
     /// True if the episode is hosted outside of Spotify’s CDN.
     pub is_externally_hosted: bool,
     /// True if the episode is playable in the given market. Otherwise false.
-    pub is_playable: bool,
+    #[serde(default)]
+    pub is_playable: Option<bool>,
     /// **Note: This field is deprecated and might be removed in the future. Please use the languages field instead.** The language used in the episode, identified by a [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
-    pub language: String,
+    #[serde(default)]
+    pub language: Option<String>,
     /// A list of the languages used in the episode, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
     pub languages: Vec<String>,
     /// The name of the episode.
     pub name: String,
     /// The date the episode was first released, for example `"1981-12-15"`. Depending on the precision, it might be shown as `"1981"` or `"1981-12"`.
-    pub release_date: String,
+    pub release_date: ReleaseDate,
     /// The precision with which `release_date` value is known: `"year"`, `"month"`, or `"day"`.
-    pub release_date_precision: String,
+    pub release_date_precision: ReleaseDatePrecision,
     /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-episoderestrictionobject) for more details.
-    pub restrictions: EpisodeRestrictionObject,
+    #[serde(default)]
+    pub restrictions: Option<EpisodeRestrictionObject>,
     /// The user’s most recent position in the episode. Set if the supplied access token is a user token and has the scope `user-read-playback-position`.
-    pub resume_point: ResumePointObject,
+    #[serde(default)]
+    pub resume_point: Option<ResumePointObject>,
     /// The object type: “episode”.
     #[serde(rename = "type")]
     pub _type: String,
@@ -764,7 +1223,8 @@ pub struct SimplifiedPlaylistObject {
     /// `true` if the owner allows other users to modify the playlist.
     pub collaborative: bool,
     /// The playlist description. Only returned for modified, verified playlists, otherwise `null`.
-    pub description: String,
+    #[serde(default)]
+    pub description: Option<String>,
     /// Known external URLs for this playlist.
     pub external_urls: ExternalUrlObject,
     /// A link to the Web API endpoint providing full details of the playlist.
@@ -778,11 +1238,12 @@ pub struct SimplifiedPlaylistObject {
     /// The user who owns the playlist
     pub owner: PublicUserObject,
     /// The playlist’s public/private status: `true` the playlist is public, `false` the playlist is private, `null` the playlist status is not relevant. For more about public/private status, see [Working with Playlists](https://developer.spotify.com/documentation/general/guides/working-with-playlists/)
-    pub public: bool,
+    #[serde(default)]
+    pub public: Option<bool>,
     /// The version identifier for the current playlist. Can be supplied in other requests to target a specific playlist version
     pub snapshot_id: String,
-    /// Information about the tracks of the playlist. Note, a track object may be `null`. This can happen if a track is no longer available.
-    pub tracks: Vec<PlaylistTrackObject>,
+    /// A collection containing a link ( `href` ) to the Web API endpoint where full details of the playlist’s tracks can be retrieved, along with the `total` number of tracks in the playlist.
+    pub tracks: PlaylistTracksRefObject,
     /// The object type: “playlist”
     #[serde(rename = "type")]
     pub _type: String,
@@ -793,7 +1254,8 @@ pub struct SimplifiedPlaylistObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SimplifiedShowObject {
     /// A list of the countries in which the show can be played, identified by their [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
-    pub available_markets: Vec<String>,
+    #[serde(default)]
+    pub available_markets: Vec<Market>,
     /// The copyright statements of the show.
     pub copyrights: Vec<CopyrightObject>,
     /// A description of the show.
@@ -809,7 +1271,8 @@ pub struct SimplifiedShowObject {
     /// The cover art for the show in various sizes, widest first.
     pub images: Vec<ImageObject>,
     /// True if all of the show’s episodes are hosted outside of Spotify’s CDN. This field might be `null` in some cases.
-    pub is_externally_hosted: bool,
+    #[serde(default)]
+    pub is_externally_hosted: Option<bool>,
     /// A list of the languages used in the show, identified by their [ISO 639](https://en.wikipedia.org/wiki/ISO_639) code.
     pub languages: Vec<String>,
     /// The media type of the show.
@@ -828,9 +1291,10 @@ pub struct SimplifiedShowObject {
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct SimplifiedTrackObject {
     /// The artists who performed the track. Each artist object includes a link in `href` to more detailed information about the artist.
-    pub artists: Vec<ArtistObject>,
+    pub artists: Vec<SimplifiedArtistObject>,
     /// A list of the countries in which the track can be played, identified by their [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
-    pub available_markets: Vec<String>,
+    #[serde(default)]
+    pub available_markets: Vec<Market>,
     /// The disc number (usually `1` unless the album consists of more than one disc).
     pub disc_number: usize,
     /// The track length in milliseconds.
@@ -846,15 +1310,19 @@ pub struct SimplifiedTrackObject {
     /// Whether or not the track is from a local file.
     pub is_local: bool,
     /// Part of the response when [Track Relinking](https://developer.spotify.com/documentation/general/guides/track-relinking-guide/) is applied. If `true`, the track is playable in the given market. Otherwise `false`.
-    pub is_playable: bool,
+    #[serde(default)]
+    pub is_playable: Option<bool>,
     /// Part of the response when [Track Relinking](https://developer.spotify.com/documentation/general/guides/track-relinking-guide/) is applied, and the requested track has been replaced with different track. The track in the `linked_from` object contains information about the originally requested track.
-    pub linked_from: LinkedFrom,
+    #[serde(default)]
+    pub linked_from: Option<LinkedFrom>,
     /// The name of the track.
     pub name: String,
     /// A link to a 30 second preview (MP3 format) of the track. Can be `null`
-    pub preview_url: String,
+    #[serde(default)]
+    pub preview_url: Option<String>,
     /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-trackrestrictionobject) for more details.
-    pub restrictions: TrackRestrictionObject,
+    #[serde(default)]
+    pub restrictions: Option<TrackRestrictionObject>,
     /// The number of the track. If an album has several discs, the track number is the number on the specified disc.
     pub track_number: usize,
     /// The object type: “track”.
@@ -869,9 +1337,10 @@ pub struct TrackObject {
     /// The album on which the track appears. The album object includes a link in `href` to full information about the album.
     pub album: SimplifiedAlbumObject,
     /// The artists who performed the track. Each artist object includes a link in `href` to more detailed information about the artist.
-    pub artists: Vec<ArtistObject>,
+    pub artists: Vec<SimplifiedArtistObject>,
     /// A list of the countries in which the track can be played, identified by their [ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2) code.
-    pub available_markets: Vec<String>,
+    #[serde(default)]
+    pub available_markets: Vec<Market>,
     /// The disc number (usually `1` unless the album consists of more than one disc).
     pub disc_number: usize,
     /// The track length in milliseconds.
@@ -889,9 +1358,11 @@ pub struct TrackObject {
     /// Whether or not the track is from a local file.
     pub is_local: bool,
     /// Part of the response when [Track Relinking](https://developer.spotify.com/documentation/general/guides/track-relinking-guide/) is applied. If `true`, the track is playable in the given market. Otherwise `false`.
-    pub is_playable: bool,
+    #[serde(default)]
+    pub is_playable: Option<bool>,
     /// Part of the response when [Track Relinking](https://developer.spotify.com/documentation/general/guides/track-relinking-guide/) is applied, and the requested track has been replaced with different track. The track in the `linked_from` object contains information about the originally requested track.
-    pub linked_from: LinkedFrom,
+    #[serde(default)]
+    pub linked_from: Option<LinkedFrom>,
     /// The name of the track.
     pub name: String,
     /// The popularity of the track. The value will be between 0 and 100, with 100 being the most popular.
@@ -899,9 +1370,11 @@ pub struct TrackObject {
     /// Generally speaking, songs that are being played a lot now will have a higher popularity than songs that were played a lot in the past. Duplicate tracks (e.g. the same track from a single and an album) are rated independently. Artist and album popularity is derived mathematically from track popularity. Note that the popularity value may lag actual popularity by a few days: the value is not updated in real time.
     pub popularity: usize,
     /// A link to a 30 second preview (MP3 format) of the track. Can be `null`
-    pub preview_url: String,
+    #[serde(default)]
+    pub preview_url: Option<String>,
     /// Included in the response when a content restriction is applied. See [Restriction Object](https://developer.spotify.com/documentation/web-api/reference/#object-trackrestrictionobject) for more details.
-    pub restrictions: TrackRestrictionObject,
+    #[serde(default)]
+    pub restrictions: Option<TrackRestrictionObject>,
     /// The number of the track. If an album has several discs, the track number is the number on the specified disc.
     pub track_number: usize,
     /// The object type: “track”.
@@ -928,37 +1401,37 @@ pub struct TrackRestrictionObject {
     /// * `market` - The content item is not available in the given market.
     /// * `product` - The content item is not available for the user’s subscription type.
     /// * `explicit` - The content item is explicit and the user’s account is set to not play explicit content. Additional reasons may be added in the future. Note: If you use this field, make sure that your application safely handles unknown values.
-    pub reason: String,
+    pub reason: RestrictionReason,
 }
 /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#object-tuneabletrackobject)
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub struct TuneableTrackObject {
     /// A confidence measure from 0.0 to 1.0 of whether the track is acoustic. 1.0 represents high confidence the track is acoustic.
-    pub acousticness: usize,
+    pub acousticness: f64,
     /// Danceability describes how suitable a track is for dancing based on a combination of musical elements including tempo, rhythm stability, beat strength, and overall regularity. A value of 0.0 is least danceable and 1.0 is most danceable.
-    pub danceability: usize,
+    pub danceability: f64,
     /// The duration of the track in milliseconds.
     pub duration_ms: usize,
     /// Energy is a measure from 0.0 to 1.0 and represents a perceptual measure of intensity and activity. Typically, energetic tracks feel fast, loud, and noisy. For example, death metal has high energy, while a Bach prelude scores low on the scale. Perceptual features contributing to this attribute include dynamic range, perceived loudness, timbre, onset rate, and general entropy.
-    pub energy: usize,
+    pub energy: f64,
     /// Predicts whether a track contains no vocals. “Ooh” and “aah” sounds are treated as instrumental in this context. Rap or spoken word tracks are clearly “vocal”. The closer the instrumentalness value is to 1.0, the greater likelihood the track contains no vocal content. Values above 0.5 are intended to represent instrumental tracks, but confidence is higher as the value approaches 1.0.
-    pub instrumentalness: usize,
-    /// The key the track is in. Integers map to pitches using standard [Pitch Class notation](https://en.wikipedia.org/wiki/Pitch_class). E.g. 0 = C, 1 = C♯/D♭, 2 = D, and so on.
-    pub key: usize,
+    pub instrumentalness: f64,
+    /// The key the track is in. Integers map to pitches using standard [Pitch Class notation](https://en.wikipedia.org/wiki/Pitch_class). E.g. 0 = C, 1 = C♯/D♭, 2 = D, and so on. If no key was detected, the value is -1.
+    pub key: i32,
     /// Detects the presence of an audience in the recording. Higher liveness values represent an increased probability that the track was performed live. A value above 0.8 provides strong likelihood that the track is live.
-    pub liveness: usize,
+    pub liveness: f64,
     /// The overall loudness of a track in decibels (dB). Loudness values are averaged across the entire track and are useful for comparing relative loudness of tracks. Loudness is the quality of a sound that is the primary psychological correlate of physical strength (amplitude). Values typical range between -60 and 0 db.
-    pub loudness: usize,
+    pub loudness: f64,
     /// Mode indicates the modality (major or minor) of a track, the type of scale from which its melodic content is derived. Major is represented by 1 and minor is 0.
-    pub mode: usize,
+    pub mode: i32,
     /// The popularity of the track. The value will be between 0 and 100, with 100 being the most popular. The popularity is calculated by algorithm and is based, in the most part, on the total number of plays the track has had and how recent those plays are. _Note: When applying track relinking via the `market` parameter, it is expected to find relinked tracks with popularities that do not match `min_*`, `max_*` and `target_*` popularities. These relinked tracks are accurate replacements for unplayable tracks with the expected popularity scores. Original, non-relinked tracks are available via the `linked_from attribute` of the [relinked track response](https://developer.spotify.com/documentation/general/guides/track-relinking-guide/)._
-    pub popularity: usize,
+    pub popularity: f64,
     /// Speechiness detects the presence of spoken words in a track. The more exclusively speech-like the recording (e.g. talk show, audio book, poetry), the closer to 1.0 the attribute value. Values above 0.66 describe tracks that are probably made entirely of spoken words. Values between 0.33 and 0.66 describe tracks that may contain both music and speech, either in sections or layered, including such cases as rap music. Values below 0.33 most likely represent music and other non-speech-like tracks.
-    pub speechiness: usize,
+    pub speechiness: f64,
     /// The overall estimated tempo of a track in beats per minute (BPM). In musical terminology, tempo is the speed or pace of a given piece and derives directly from the average beat duration.
-    pub tempo: usize,
+    pub tempo: f64,
     /// An estimated overall time signature of a track. The time signature (meter) is a notational convention to specify how many beats are in each bar (or measure).
     pub time_signature: usize,
     /// A measure from 0.0 to 1.0 describing the musical positiveness conveyed by a track. Tracks with high valence sound more positive (e.g. happy, cheerful, euphoric), while tracks with low valence sound more negative (e.g. sad, depressed, angry).
-    pub valence: usize,
+    pub valence: f64,
 }
@@ -0,0 +1,361 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use reqwest::Url;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::objects::{CursorPagingObject, PagingObject};
+use crate::{Error, Spotify};
+
+/// Yields the items of a paged response, following the `next` links one page at a time as the items are
+/// consumed.
+///
+/// Both offset and cursor paged responses are followed. Pages are only requested when needed, so
+/// [`Iterator::take`] stops fetching once enough items have been yielded. To resume later, save
+/// [`offset`](Paginator::offset) and pass it to the endpoint method as `offset`, or save
+/// [`cursor`](Paginator::cursor) and pass it as the argument it names.
+pub struct Paginator<'a, T> {
+    spotify: &'a Spotify,
+    items: VecDeque<T>,
+    next: Option<String>,
+    offset: usize,
+    cursor: Option<Cursor>,
+}
+
+/// Where to resume a cursor paged response, taken from the query parameter of a `next` link.
+///
+/// [`Spotify::get_followed_artists`] walks forwards with `after`, while [`Spotify::get_recently_played`]
+/// walks backwards in time with `before`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Cursor {
+    /// Passed to the endpoint method as `after`.
+    After(String),
+    /// Passed to the endpoint method as `before`.
+    Before(String),
+}
+
+impl Cursor {
+    pub fn after(&self) -> Option<&str> {
+        match self {
+            Cursor::After(cursor) => Some(cursor),
+            Cursor::Before(_) => None,
+        }
+    }
+
+    pub fn before(&self) -> Option<&str> {
+        match self {
+            Cursor::After(_) => None,
+            Cursor::Before(cursor) => Some(cursor),
+        }
+    }
+
+    /// [`after`](Cursor::after) as the Unix timestamp in milliseconds taken by
+    /// [`Spotify::get_recently_played`].
+    pub fn after_timestamp(&self) -> Option<u64> {
+        self.after().and_then(|cursor| cursor.parse().ok())
+    }
+
+    /// [`before`](Cursor::before) as the Unix timestamp in milliseconds taken by
+    /// [`Spotify::get_recently_played`].
+    pub fn before_timestamp(&self) -> Option<u64> {
+        self.before().and_then(|cursor| cursor.parse().ok())
+    }
+
+    /// The `after` or `before` query parameter of `url`, if it has one.
+    fn from_url(url: &str) -> Option<Self> {
+        let url = Url::parse(url).ok()?;
+        url.query_pairs()
+            .find_map(|(key, value)| match key.as_ref() {
+                "after" => Some(Cursor::After(value.into_owned())),
+                "before" => Some(Cursor::Before(value.into_owned())),
+                _ => None,
+            })
+    }
+}
+
+impl<'a, T> Paginator<'a, T>
+where
+    T: DeserializeOwned,
+{
+    /// The offset of the next item to be yielded. Only meaningful for offset paged responses.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The cursor to resume from so that no item is skipped. Only set for cursor paged responses.
+    ///
+    /// It only moves on to the next page once every item of the current page has been yielded, so
+    /// resuming part-way through a page yields the rest of that page again from its start. `None` if the
+    /// current page was requested without a cursor, or once the last page has been yielded.
+    pub fn cursor(&self) -> Option<&Cursor> {
+        self.cursor.as_ref()
+    }
+
+    fn fetch(&mut self, url: String) -> Result<(), Error> {
+        let page = unwrap_page(self.spotify.get(url, None)?);
+        if page.get("offset").is_some() {
+            let page: PagingObject<T> = serde_json::from_value(page)?;
+            self.offset = page.offset;
+            self.next = page.next;
+            self.items = page.items.into();
+        } else {
+            let page: CursorPagingObject<T> = serde_json::from_value(page)?;
+            self.next = page.next;
+            self.items = page.items.into();
+        }
+        if self.items.is_empty() {
+            self.cursor = self.next.as_deref().and_then(Cursor::from_url);
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T> Iterator for Paginator<'a, T>
+where
+    T: DeserializeOwned,
+{
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.pop_front() {
+                self.offset += 1;
+                if self.items.is_empty() {
+                    self.cursor = self.next.as_deref().and_then(Cursor::from_url);
+                }
+                return Some(Ok(item));
+            }
+
+            // A failed page ends the iteration, since its `next` link is unknown.
+            let url = self.next.take()?;
+            if let Err(err) = self.fetch(url) {
+                return Some(Err(err));
+            }
+        }
+    }
+}
+
+/// The most times a page is retried after the Web API rate limits it.
+const MAX_RATE_LIMIT_RETRIES: usize = 5;
+
+/// Some endpoints wrap their page in an object, such as `{"artists": {...}}` or
+/// `{"message": "...", "playlists": {...}}`, and so do their `next` links. The page is the entry holding
+/// `items`.
+fn unwrap_page(value: Value) -> Value {
+    match value {
+        Value::Object(mut map) if !map.contains_key("items") => {
+            let key = map
+                .iter()
+                .find(|(_, page)| page.get("items").is_some())
+                .map(|(key, _)| key.clone());
+            match key {
+                Some(key) => map.remove(&key).unwrap(),
+                None => Value::Object(map),
+            }
+        }
+        value => value,
+    }
+}
+
+/// When the workers of [`Spotify::fetch_all`] may send their next request. Shared so that a rate limit hit
+/// by one worker pauses all of them, rather than the others running into it as well.
+struct Backoff {
+    resume_at: Mutex<Option<Instant>>,
+}
+
+impl Backoff {
+    fn new() -> Self {
+        Backoff {
+            resume_at: Mutex::new(None),
+        }
+    }
+
+    /// Sleeps until the latest `Retry-After` reported by any worker has passed.
+    fn wait(&self) {
+        let resume_at = *self.resume_at.lock().unwrap();
+        if let Some(resume_at) = resume_at {
+            let now = Instant::now();
+            if resume_at > now {
+                thread::sleep(resume_at - now);
+            }
+        }
+    }
+
+    fn pause_for(&self, retry_after: Duration) {
+        let until = Instant::now() + retry_after;
+        let mut resume_at = self.resume_at.lock().unwrap();
+        *resume_at = Some(resume_at.map_or(until, |resume_at| resume_at.max(until)));
+    }
+}
+
+/// The link to the page at `offset`, keeping every other query parameter of `href`.
+fn page_url(href: &Url, offset: usize, limit: usize) -> String {
+    let params: Vec<(String, String)> = href
+        .query_pairs()
+        .filter(|(key, _)| key != "offset" && key != "limit")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+
+    let mut url = href.clone();
+    url.query_pairs_mut()
+        .clear()
+        .extend_pairs(params)
+        .append_pair("offset", &offset.to_string())
+        .append_pair("limit", &limit.to_string());
+    url.to_string()
+}
+
+impl<T> PagingObject<T> {
+    /// The links to every page after this one, found from the `href`, `limit` and `total` of this page.
+    ///
+    /// Empty once this page reaches the `total`. Fails if `href` is not a URL.
+    pub fn remaining_page_urls(&self) -> Result<Vec<String>, Error> {
+        let start = self.offset + self.items.len();
+        if start >= self.total || self.limit == 0 {
+            return Ok(Vec::new());
+        }
+
+        let href = Url::parse(&self.href).map_err(|err| {
+            Error::InvalidArgument(format!("page href {:?} is not a URL: {}", self.href, err))
+        })?;
+        Ok((start..self.total)
+            .step_by(self.limit)
+            .map(|offset| page_url(&href, offset, self.limit))
+            .collect())
+    }
+}
+
+impl Spotify {
+    /// Yields the items of `page` and of every page after it.
+    pub fn paginate<T>(&self, page: PagingObject<T>) -> Paginator<'_, T>
+    where
+        T: DeserializeOwned,
+    {
+        Paginator {
+            spotify: self,
+            items: page.items.into(),
+            next: page.next,
+            offset: page.offset,
+            cursor: None,
+        }
+    }
+
+    /// Yields the items of `page` and of every page after it.
+    pub fn paginate_cursor<T>(&self, page: CursorPagingObject<T>) -> Paginator<'_, T>
+    where
+        T: DeserializeOwned,
+    {
+        let cursor = if page.items.is_empty() {
+            page.next.as_deref().and_then(Cursor::from_url)
+        } else {
+            Cursor::from_url(&page.href)
+        };
+        Paginator {
+            spotify: self,
+            items: page.items.into(),
+            next: page.next,
+            offset: 0,
+            cursor,
+        }
+    }
+
+    /// Fetches every page after `first` concurrently and returns all of the items in order.
+    ///
+    /// Since `first` reveals the `total`, the offsets of the remaining pages are known up front and are
+    /// fetched by up to `concurrency` threads. When a page is rate limited, every thread waits out the delay
+    /// the Web API asks for before the page is retried.
+    pub fn fetch_all<T>(&self, first: PagingObject<T>, concurrency: usize) -> Result<Vec<T>, Error>
+    where
+        T: DeserializeOwned + Send,
+    {
+        fetch_all_with(first, concurrency, |url| self.get(url.to_string(), None))
+    }
+}
+
+/// Like [`Spotify::fetch_all`], with each remaining page requested by `fetch`, which is given the page link
+/// and returns the response body.
+///
+/// The first error other than a rate limit, or a page rate limited more than a few times, stops every
+/// thread from taking another page and is returned.
+pub fn fetch_all_with<T, F>(
+    first: PagingObject<T>,
+    concurrency: usize,
+    fetch: F,
+) -> Result<Vec<T>, Error>
+where
+    T: DeserializeOwned + Send,
+    F: Fn(&str) -> Result<Value, Error> + Sync,
+{
+    if concurrency == 0 {
+        return Err(Error::InvalidArgument(
+            "concurrency must be at least 1".to_string(),
+        ));
+    }
+
+    let urls = first.remaining_page_urls()?;
+    if urls.is_empty() {
+        return Ok(first.items);
+    }
+
+    let pages: Vec<Mutex<Option<Vec<T>>>> = urls.iter().map(|_| Mutex::new(None)).collect();
+    let next_page = AtomicUsize::new(0);
+    let failure: Mutex<Option<Error>> = Mutex::new(None);
+    let backoff = Backoff::new();
+
+    thread::scope(|scope| {
+        for _ in 0..concurrency.min(urls.len()) {
+            scope.spawn(|| loop {
+                if failure.lock().unwrap().is_some() {
+                    return;
+                }
+                let index = next_page.fetch_add(1, Ordering::SeqCst);
+                let url = match urls.get(index) {
+                    Some(url) => url,
+                    None => return,
+                };
+                match fetch_page(&fetch, url, &backoff) {
+                    Ok(items) => *pages[index].lock().unwrap() = Some(items),
+                    Err(err) => {
+                        failure.lock().unwrap().get_or_insert(err);
+                        return;
+                    }
+                }
+            });
+        }
+    });
+
+    if let Some(err) = failure.into_inner().unwrap() {
+        return Err(err);
+    }
+    let mut items = first.items;
+    for page in pages {
+        items.extend(page.into_inner().unwrap().unwrap_or_default());
+    }
+    Ok(items)
+}
+
+fn fetch_page<T, F>(fetch: &F, url: &str, backoff: &Backoff) -> Result<Vec<T>, Error>
+where
+    T: DeserializeOwned,
+    F: Fn(&str) -> Result<Value, Error>,
+{
+    let mut retries = 0;
+    loop {
+        backoff.wait();
+        match fetch(url) {
+            Ok(page) => {
+                let page: PagingObject<T> = serde_json::from_value(unwrap_page(page))?;
+                return Ok(page.items);
+            }
+            Err(Error::RateLimited(retry_after)) if retries < MAX_RATE_LIMIT_RETRIES => {
+                backoff.pause_for(retry_after);
+                retries += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
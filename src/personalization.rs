@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+
+use crate::objects::{ArtistObject, PagingObject, TrackObject};
+use crate::{insert_optional, Error, Spotify};
+
+/// The time frame over which affinities are computed. The Web API defaults to [`TimeRange::MediumTerm`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeRange {
+    /// Approximately the last 4 weeks.
+    ShortTerm,
+    /// Approximately the last 6 months.
+    MediumTerm,
+    /// Several years of data, including all new data as it becomes available.
+    LongTerm,
+}
+
+impl TimeRange {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeRange::ShortTerm => "short_term",
+            TimeRange::MediumTerm => "medium_term",
+            TimeRange::LongTerm => "long_term",
+        }
+    }
+}
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-users-top-artists-and-tracks)
+    pub fn get_user_top_artists(
+        &self,
+        time_range: Option<TimeRange>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<ArtistObject>, Error> {
+        self.get_user_top("artists", time_range, limit, offset)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-users-top-artists-and-tracks)
+    pub fn get_user_top_tracks(
+        &self,
+        time_range: Option<TimeRange>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<TrackObject>, Error> {
+        self.get_user_top("tracks", time_range, limit, offset)
+    }
+
+    fn get_user_top<T>(
+        &self,
+        kind: &str,
+        time_range: Option<TimeRange>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<T>, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "time_range", time_range.map(|t| t.as_str()));
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "offset", offset);
+
+        self.get(format!("me/top/{}", kind), Some(params))
+    }
+}
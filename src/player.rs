@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use crate::body::{Nothing, Offset, StartPlaybackBody, TransferPlaybackBody};
+use crate::objects::{
+    CurrentlyPlayingContextObject, CurrentlyPlayingObject, CursorPagingObject, DevicesObject,
+    PlayHistoryObject, QueueObject, RepeatState,
+};
+use crate::{insert_optional, Error, Market, Method, Spotify};
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-information-about-the-users-current-playback)
+    ///
+    /// Returns `None` if there is no active device.
+    pub fn get_playback_state(
+        &self,
+        market: Option<Market>,
+    ) -> Result<Option<CurrentlyPlayingContextObject>, Error> {
+        let mut params = HashMap::new();
+        params.insert("additional_types", "episode".to_string());
+        insert_optional(&mut params, "market", market);
+
+        self.get_optional("me/player".to_string(), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-the-users-currently-playing-track)
+    ///
+    /// Returns `None` if nothing is playing.
+    pub fn get_currently_playing(
+        &self,
+        market: Option<Market>,
+    ) -> Result<Option<CurrentlyPlayingObject>, Error> {
+        let mut params = HashMap::new();
+        params.insert("additional_types", "episode".to_string());
+        insert_optional(&mut params, "market", market);
+
+        self.get_optional("me/player/currently-playing".to_string(), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-a-users-available-devices)
+    pub fn get_available_devices(&self) -> Result<DevicesObject, Error> {
+        self.get("me/player/devices".to_string(), None)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-recently-played)
+    ///
+    /// `after` and `before` are Unix timestamps in milliseconds, usually taken from the `cursors` of the
+    /// previous page. Only one of them may be given.
+    pub fn get_recently_played(
+        &self,
+        limit: Option<usize>,
+        after: Option<u64>,
+        before: Option<u64>,
+    ) -> Result<CursorPagingObject<PlayHistoryObject>, Error> {
+        if after.is_some() && before.is_some() {
+            return Err(Error::InvalidArgument(
+                "only one of after and before may be given".to_string(),
+            ));
+        }
+
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "after", after);
+        insert_optional(&mut params, "before", before);
+
+        self.get("me/player/recently-played".to_string(), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-queue)
+    pub fn get_queue(&self) -> Result<QueueObject, Error> {
+        self.get("me/player/queue".to_string(), None)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-start-a-users-playback)
+    ///
+    /// Plays `context_uri` or `uris`, or resumes the current playback if neither is given.
+    pub fn start_resume_playback(
+        &self,
+        device_id: Option<&str>,
+        context_uri: Option<&str>,
+        uris: Option<&[&str]>,
+        offset: Option<Offset>,
+        position_ms: Option<usize>,
+    ) -> Result<(), Error> {
+        if context_uri.is_some() && uris.is_some() {
+            return Err(Error::InvalidArgument(
+                "only one of context_uri and uris may be given".to_string(),
+            ));
+        }
+
+        let body = StartPlaybackBody {
+            context_uri: context_uri.map(|uri| uri.to_string()),
+            uris: uris.map(|uris| uris.iter().map(|uri| uri.to_string()).collect()),
+            offset,
+            position_ms,
+        };
+        self.player_command(Method::PUT, "play", HashMap::new(), device_id, Some(&body))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-pause-a-users-playback)
+    pub fn pause(&self, device_id: Option<&str>) -> Result<(), Error> {
+        self.player_command::<Nothing>(Method::PUT, "pause", HashMap::new(), device_id, None)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-skip-users-playback-to-next-track)
+    pub fn next(&self, device_id: Option<&str>) -> Result<(), Error> {
+        self.player_command::<Nothing>(Method::POST, "next", HashMap::new(), device_id, None)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-skip-users-playback-to-previous-track)
+    pub fn previous(&self, device_id: Option<&str>) -> Result<(), Error> {
+        self.player_command::<Nothing>(Method::POST, "previous", HashMap::new(), device_id, None)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-seek-to-position-in-currently-playing-track)
+    ///
+    /// A position past the end of the track starts the next one.
+    pub fn seek(&self, position_ms: usize, device_id: Option<&str>) -> Result<(), Error> {
+        let mut params = HashMap::new();
+        params.insert("position_ms", position_ms.to_string());
+        self.player_command::<Nothing>(Method::PUT, "seek", params, device_id, None)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-set-repeat-mode-on-users-playback)
+    pub fn set_repeat(&self, state: RepeatState, device_id: Option<&str>) -> Result<(), Error> {
+        let mut params = HashMap::new();
+        params.insert("state", state.as_str().to_string());
+        self.player_command::<Nothing>(Method::PUT, "repeat", params, device_id, None)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-set-volume-for-users-playback)
+    pub fn set_volume(&self, volume_percent: u8, device_id: Option<&str>) -> Result<(), Error> {
+        if volume_percent > 100 {
+            return Err(Error::InvalidArgument(format!(
+                "volume {} is not between 0 and 100",
+                volume_percent
+            )));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("volume_percent", volume_percent.to_string());
+        self.player_command::<Nothing>(Method::PUT, "volume", params, device_id, None)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-toggle-shuffle-for-users-playback)
+    pub fn toggle_shuffle(&self, state: bool, device_id: Option<&str>) -> Result<(), Error> {
+        let mut params = HashMap::new();
+        params.insert("state", state.to_string());
+        self.player_command::<Nothing>(Method::PUT, "shuffle", params, device_id, None)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-transfer-a-users-playback)
+    pub fn transfer_playback(&self, device_id: &str, play: bool) -> Result<(), Error> {
+        let body = TransferPlaybackBody {
+            device_ids: vec![device_id.to_string()],
+            play,
+        };
+        self.request(Method::PUT, "me/player".to_string(), None, Some(&body))?;
+        Ok(())
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-add-to-queue)
+    ///
+    /// `uri` is the URI of a track or episode.
+    pub fn add_to_queue(&self, uri: &str, device_id: Option<&str>) -> Result<(), Error> {
+        let mut params = HashMap::new();
+        params.insert("uri", uri.to_string());
+        self.player_command::<Nothing>(Method::POST, "queue", params, device_id, None)
+    }
+
+    fn player_command<T>(
+        &self,
+        method: Method,
+        command: &str,
+        mut params: HashMap<&str, String>,
+        device_id: Option<&str>,
+        body: Option<&T>,
+    ) -> Result<(), Error>
+    where
+        T: serde::Serialize,
+    {
+        insert_optional(&mut params, "device_id", device_id);
+        self.request(method, format!("me/player/{}", command), Some(params), body)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,339 @@
+use std::collections::HashMap;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::body::{
+    AddItemsBody, PlaylistDetailsBody, RemoveItem, RemoveItemsBody, ReorderItemsBody,
+    ReplaceItemsBody,
+};
+use crate::objects::{
+    ImageObject, PagingObject, PlaylistObject, PlaylistTrackObject, SimplifiedPlaylistObject,
+};
+use crate::{insert_optional, Error, Market, Method, Spotify};
+
+/// Playlists may hold episodes as well as tracks, and both are decoded.
+const ADDITIONAL_TYPES: &str = "track,episode";
+
+/// The most items a single request may add, remove or replace.
+pub const MAX_ITEMS_PER_REQUEST: usize = 100;
+
+/// The largest base64 encoded cover image the Web API accepts, in bytes.
+pub const MAX_COVER_IMAGE_SIZE: usize = 256 * 1024;
+
+/// Checks that `jpeg` is a JPEG image small enough for a playlist cover and encodes it as base64.
+pub fn encode_cover_image(jpeg: &[u8]) -> Result<String, Error> {
+    if !jpeg.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        return Err(Error::InvalidArgument(
+            "cover image is not a JPEG image".to_string(),
+        ));
+    }
+
+    let encoded = STANDARD.encode(jpeg);
+    if encoded.len() > MAX_COVER_IMAGE_SIZE {
+        return Err(Error::InvalidArgument(format!(
+            "encoded cover image is {} bytes, at most {} are allowed",
+            encoded.len(),
+            MAX_COVER_IMAGE_SIZE
+        )));
+    }
+    Ok(encoded)
+}
+
+/// The bodies that add `uris` in order, [`MAX_ITEMS_PER_REQUEST`] at a time, the first chunk at `position`
+/// if given and each following chunk right after the one before it.
+pub fn add_items_bodies(uris: &[&str], position: Option<usize>) -> Vec<AddItemsBody> {
+    uris.chunks(MAX_ITEMS_PER_REQUEST)
+        .enumerate()
+        .map(|(i, chunk)| AddItemsBody {
+            uris: chunk.iter().map(|uri| uri.to_string()).collect(),
+            position: position.map(|position| position + i * MAX_ITEMS_PER_REQUEST),
+        })
+        .collect()
+}
+
+/// The body that replaces every item with the first [`MAX_ITEMS_PER_REQUEST`] of `uris`, followed by the
+/// bodies that append the rest.
+pub fn replace_items_bodies(uris: &[&str]) -> (ReplaceItemsBody, Vec<AddItemsBody>) {
+    let (first, rest) = uris.split_at(uris.len().min(MAX_ITEMS_PER_REQUEST));
+    let replace = ReplaceItemsBody {
+        uris: first.iter().map(|uri| uri.to_string()).collect(),
+    };
+    (replace, add_items_bodies(rest, None))
+}
+
+#[derive(Deserialize)]
+struct Snapshot {
+    snapshot_id: String,
+}
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-playlist)
+    ///
+    /// Only the first page of items is included; use [`get_playlist_items`](Spotify::get_playlist_items) for the rest.
+    pub fn get_playlist(
+        &self,
+        playlist_id: &str,
+        market: Option<Market>,
+    ) -> Result<PlaylistObject, Error> {
+        self.get_playlist_fields(playlist_id, None, market)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-playlist)
+    ///
+    /// Returns only the `fields` asked for, for example `name,tracks.items(added_at,track(name))`, decoded into
+    /// a type of the caller's choosing such as `serde_json::Value` or a struct with just those fields.
+    pub fn get_playlist_fields<T>(
+        &self,
+        playlist_id: &str,
+        fields: Option<&str>,
+        market: Option<Market>,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let mut params = HashMap::new();
+        params.insert("additional_types", ADDITIONAL_TYPES.to_string());
+        insert_optional(&mut params, "fields", fields);
+        insert_optional(&mut params, "market", market);
+
+        self.get(format!("playlists/{}", playlist_id), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-playlists-tracks)
+    pub fn get_playlist_items(
+        &self,
+        playlist_id: &str,
+        market: Option<Market>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<PlaylistTrackObject>, Error> {
+        self.get_playlist_items_fields(playlist_id, None, market, limit, offset)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-playlists-tracks)
+    ///
+    /// Returns only the `fields` asked for, for example `total,items(track(id,name))`, decoded into a type of
+    /// the caller's choosing.
+    pub fn get_playlist_items_fields<T>(
+        &self,
+        playlist_id: &str,
+        fields: Option<&str>,
+        market: Option<Market>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let mut params = HashMap::new();
+        params.insert("additional_types", ADDITIONAL_TYPES.to_string());
+        insert_optional(&mut params, "fields", fields);
+        insert_optional(&mut params, "market", market);
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "offset", offset);
+
+        self.get(format!("playlists/{}/tracks", playlist_id), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-a-list-of-current-users-playlists)
+    pub fn get_current_user_playlists(
+        &self,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<SimplifiedPlaylistObject>, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "offset", offset);
+
+        self.get("me/playlists".to_string(), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-list-users-playlists)
+    pub fn get_user_playlists(
+        &self,
+        user_id: &str,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<SimplifiedPlaylistObject>, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "offset", offset);
+
+        self.get(format!("users/{}/playlists", user_id), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-create-playlist)
+    pub fn create_playlist(
+        &self,
+        user_id: &str,
+        name: &str,
+        public: Option<bool>,
+        collaborative: Option<bool>,
+        description: Option<&str>,
+    ) -> Result<PlaylistObject, Error> {
+        let body = PlaylistDetailsBody {
+            name: Some(name.to_string()),
+            public,
+            collaborative,
+            description: description.map(|description| description.to_string()),
+        };
+        self.request_json(Method::POST, format!("users/{}/playlists", user_id), &body)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-change-playlist-details)
+    ///
+    /// Only the details that are given are changed.
+    pub fn change_playlist_details(
+        &self,
+        playlist_id: &str,
+        name: Option<&str>,
+        public: Option<bool>,
+        collaborative: Option<bool>,
+        description: Option<&str>,
+    ) -> Result<(), Error> {
+        let body = PlaylistDetailsBody {
+            name: name.map(|name| name.to_string()),
+            public,
+            collaborative,
+            description: description.map(|description| description.to_string()),
+        };
+        self.request(
+            Method::PUT,
+            format!("playlists/{}", playlist_id),
+            None,
+            Some(&body),
+        )?;
+        Ok(())
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-add-tracks-to-playlist)
+    ///
+    /// Sends the items in chunks of [`MAX_ITEMS_PER_REQUEST`], keeping them in order at `position`, and
+    /// returns the snapshot id after the last chunk.
+    pub fn add_items_to_playlist(
+        &self,
+        playlist_id: &str,
+        uris: &[&str],
+        position: Option<usize>,
+    ) -> Result<String, Error> {
+        if uris.is_empty() {
+            return Err(Error::InvalidArgument("no items to add".to_string()));
+        }
+
+        self.add_items(playlist_id, add_items_bodies(uris, position))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-remove-tracks-playlist)
+    ///
+    /// Sends the items in chunks of [`MAX_ITEMS_PER_REQUEST`] and returns the snapshot id after the last chunk.
+    /// Positions are resolved against `snapshot_id` if given.
+    pub fn remove_playlist_items(
+        &self,
+        playlist_id: &str,
+        items: &[RemoveItem],
+        snapshot_id: Option<&str>,
+    ) -> Result<String, Error> {
+        if items.is_empty() {
+            return Err(Error::InvalidArgument("no items to remove".to_string()));
+        }
+
+        let mut new_snapshot_id = String::new();
+        for chunk in items.chunks(MAX_ITEMS_PER_REQUEST) {
+            let body = RemoveItemsBody {
+                tracks: chunk.to_vec(),
+                snapshot_id: snapshot_id.map(|snapshot_id| snapshot_id.to_string()),
+            };
+            let snapshot: Snapshot = self.request_json(
+                Method::DELETE,
+                format!("playlists/{}/tracks", playlist_id),
+                &body,
+            )?;
+            new_snapshot_id = snapshot.snapshot_id;
+        }
+        Ok(new_snapshot_id)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-reorder-or-replace-playlists-tracks)
+    ///
+    /// Moves `range_length` items, defaulting to 1, from `range_start` to before `insert_before`.
+    pub fn reorder_playlist_items(
+        &self,
+        playlist_id: &str,
+        range_start: usize,
+        insert_before: usize,
+        range_length: Option<usize>,
+        snapshot_id: Option<&str>,
+    ) -> Result<String, Error> {
+        let body = ReorderItemsBody {
+            range_start,
+            insert_before,
+            range_length,
+            snapshot_id: snapshot_id.map(|snapshot_id| snapshot_id.to_string()),
+        };
+        let snapshot: Snapshot = self.request_json(
+            Method::PUT,
+            format!("playlists/{}/tracks", playlist_id),
+            &body,
+        )?;
+        Ok(snapshot.snapshot_id)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-reorder-or-replace-playlists-tracks)
+    ///
+    /// Replaces every item with `uris`; an empty list clears the playlist. Items past the first
+    /// [`MAX_ITEMS_PER_REQUEST`] are added afterwards.
+    pub fn replace_playlist_items(
+        &self,
+        playlist_id: &str,
+        uris: &[&str],
+    ) -> Result<String, Error> {
+        let (replace, rest) = replace_items_bodies(uris);
+        let snapshot: Snapshot = self.request_json(
+            Method::PUT,
+            format!("playlists/{}/tracks", playlist_id),
+            &replace,
+        )?;
+
+        if rest.is_empty() {
+            Ok(snapshot.snapshot_id)
+        } else {
+            self.add_items(playlist_id, rest)
+        }
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-playlist-cover)
+    pub fn get_playlist_cover_image(&self, playlist_id: &str) -> Result<Vec<ImageObject>, Error> {
+        self.get(format!("playlists/{}/images", playlist_id), None)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-upload-custom-playlist-cover)
+    ///
+    /// `jpeg` is the raw image, which is checked and encoded with [`encode_cover_image`] before sending.
+    pub fn upload_playlist_cover_image(&self, playlist_id: &str, jpeg: &[u8]) -> Result<(), Error> {
+        let encoded = encode_cover_image(jpeg)?;
+        self.request_bytes(
+            Method::PUT,
+            format!("playlists/{}/images", playlist_id),
+            "image/jpeg",
+            encoded.into_bytes(),
+        )?;
+        Ok(())
+    }
+
+    /// Sends each body in turn and returns the snapshot id after the last one.
+    fn add_items(&self, playlist_id: &str, bodies: Vec<AddItemsBody>) -> Result<String, Error> {
+        let mut snapshot_id = String::new();
+        for body in bodies {
+            let snapshot: Snapshot = self.request_json(
+                Method::POST,
+                format!("playlists/{}/tracks", playlist_id),
+                &body,
+            )?;
+            snapshot_id = snapshot.snapshot_id;
+        }
+        Ok(snapshot_id)
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Deserialize;
+
+use crate::objects::RecommendationsObject;
+use crate::{insert_optional, Error, Market, Spotify};
+
+/// The most seeds, across artists, genres and tracks, accepted by a single request.
+pub const MAX_SEEDS: usize = 5;
+
+/// Builds a [Get Recommendations](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-recommendations) request.
+///
+/// Setters never fail; the seeds and attribute ranges are checked by [`validate`](RecommendationsRequest::validate),
+/// which [`Spotify::get_recommendations`] calls before sending.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RecommendationsRequest {
+    seed_artists: Vec<String>,
+    seed_genres: Vec<String>,
+    seed_tracks: Vec<String>,
+    limit: Option<usize>,
+    market: Option<Market>,
+    attributes: BTreeMap<String, f64>,
+}
+
+macro_rules! tuneable_attributes {
+    ($($attr:ident: $ty:ty, $lo:expr, $hi:expr, $min:ident, $max:ident, $target:ident;)*) => {
+        /// Every tunable attribute with the lowest and highest value it accepts.
+        const TUNEABLE_ATTRIBUTES: &[(&str, f64, f64)] = &[$((stringify!($attr), $lo, $hi)),*];
+
+        impl RecommendationsRequest {
+            $(
+                #[doc = concat!("Only tracks with `", stringify!($attr), "` of at least `value`.")]
+                pub fn $min(self, value: $ty) -> Self {
+                    self.attribute("min", stringify!($attr), value as f64)
+                }
+
+                #[doc = concat!("Only tracks with `", stringify!($attr), "` of at most `value`.")]
+                pub fn $max(self, value: $ty) -> Self {
+                    self.attribute("max", stringify!($attr), value as f64)
+                }
+
+                #[doc = concat!("Prefers tracks with `", stringify!($attr), "` closest to `value`.")]
+                pub fn $target(self, value: $ty) -> Self {
+                    self.attribute("target", stringify!($attr), value as f64)
+                }
+            )*
+        }
+    };
+}
+
+tuneable_attributes! {
+    acousticness: f64, 0.0, 1.0, min_acousticness, max_acousticness, target_acousticness;
+    danceability: f64, 0.0, 1.0, min_danceability, max_danceability, target_danceability;
+    duration_ms: u32, 0.0, f64::MAX, min_duration_ms, max_duration_ms, target_duration_ms;
+    energy: f64, 0.0, 1.0, min_energy, max_energy, target_energy;
+    instrumentalness: f64, 0.0, 1.0, min_instrumentalness, max_instrumentalness, target_instrumentalness;
+    key: u8, 0.0, 11.0, min_key, max_key, target_key;
+    liveness: f64, 0.0, 1.0, min_liveness, max_liveness, target_liveness;
+    loudness: f64, f64::MIN, f64::MAX, min_loudness, max_loudness, target_loudness;
+    mode: u8, 0.0, 1.0, min_mode, max_mode, target_mode;
+    popularity: u8, 0.0, 100.0, min_popularity, max_popularity, target_popularity;
+    speechiness: f64, 0.0, 1.0, min_speechiness, max_speechiness, target_speechiness;
+    tempo: f64, 0.0, f64::MAX, min_tempo, max_tempo, target_tempo;
+    time_signature: u8, 3.0, 7.0, min_time_signature, max_time_signature, target_time_signature;
+    valence: f64, 0.0, 1.0, min_valence, max_valence, target_valence;
+}
+
+impl RecommendationsRequest {
+    pub fn new() -> Self {
+        RecommendationsRequest::default()
+    }
+
+    /// A [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) of a seed artist.
+    pub fn seed_artist(mut self, artist_id: &str) -> Self {
+        self.seed_artists.push(artist_id.to_string());
+        self
+    }
+
+    /// A genre from [`Spotify::get_available_genre_seeds`].
+    pub fn seed_genre(mut self, genre: &str) -> Self {
+        self.seed_genres.push(genre.to_string());
+        self
+    }
+
+    /// A [Spotify ID](https://developer.spotify.com/documentation/web-api/#spotify-uris-and-ids) of a seed track.
+    pub fn seed_track(mut self, track_id: &str) -> Self {
+        self.seed_tracks.push(track_id.to_string());
+        self
+    }
+
+    /// The target size of the list of recommended tracks, between 1 and 100. Defaults to 20.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Only tracks playable in this market are recommended.
+    pub fn market(mut self, market: Market) -> Self {
+        self.market = Some(market);
+        self
+    }
+
+    /// Checks the seed count, the limit and every tunable attribute.
+    ///
+    /// Only the last value set for an attribute is checked, so a later setter call can fix an earlier one.
+    pub fn validate(&self) -> Result<(), Error> {
+        let seeds = self.seed_artists.len() + self.seed_genres.len() + self.seed_tracks.len();
+        if seeds == 0 {
+            return Err(Error::InvalidArgument(
+                "at least one seed artist, genre or track is required".to_string(),
+            ));
+        }
+        if seeds > MAX_SEEDS {
+            return Err(Error::InvalidArgument(format!(
+                "{} seeds given, at most {} are allowed",
+                seeds, MAX_SEEDS
+            )));
+        }
+
+        if let Some(limit) = self.limit {
+            if !(1..=100).contains(&limit) {
+                return Err(Error::InvalidArgument(format!(
+                    "limit {} is not between 1 and 100",
+                    limit
+                )));
+            }
+        }
+
+        for &(attr, lo, hi) in TUNEABLE_ATTRIBUTES {
+            for prefix in ["min", "max", "target"] {
+                let name = format!("{}_{}", prefix, attr);
+                if let Some(&value) = self.attributes.get(&name) {
+                    if !value.is_finite() || value < lo || value > hi {
+                        return Err(Error::InvalidArgument(format!(
+                            "{} {} is not between {} and {}",
+                            name, value, lo, hi
+                        )));
+                    }
+                }
+            }
+
+            let min = self.attributes.get(&format!("min_{}", attr));
+            let max = self.attributes.get(&format!("max_{}", attr));
+            let target = self.attributes.get(&format!("target_{}", attr));
+            if let (Some(min), Some(max)) = (min, max) {
+                if min > max {
+                    return Err(Error::InvalidArgument(format!(
+                        "min_{} {} is greater than max_{} {}",
+                        attr, min, attr, max
+                    )));
+                }
+            }
+            if let (Some(min), Some(target)) = (min, target) {
+                if target < min {
+                    return Err(Error::InvalidArgument(format!(
+                        "target_{} {} is less than min_{} {}",
+                        attr, target, attr, min
+                    )));
+                }
+            }
+            if let (Some(max), Some(target)) = (max, target) {
+                if target > max {
+                    return Err(Error::InvalidArgument(format!(
+                        "target_{} {} is greater than max_{} {}",
+                        attr, target, attr, max
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn attribute(mut self, prefix: &str, attr: &str, value: f64) -> Self {
+        self.attributes
+            .insert(format!("{}_{}", prefix, attr), value);
+        self
+    }
+
+    fn to_query(&self) -> HashMap<&str, String> {
+        let mut params = HashMap::new();
+        if !self.seed_artists.is_empty() {
+            params.insert("seed_artists", self.seed_artists.join(","));
+        }
+        if !self.seed_genres.is_empty() {
+            params.insert("seed_genres", self.seed_genres.join(","));
+        }
+        if !self.seed_tracks.is_empty() {
+            params.insert("seed_tracks", self.seed_tracks.join(","));
+        }
+        insert_optional(&mut params, "limit", self.limit);
+        insert_optional(&mut params, "market", self.market);
+        for (name, value) in &self.attributes {
+            params.insert(name.as_str(), value.to_string());
+        }
+        params
+    }
+}
+
+#[derive(Deserialize)]
+struct Genres {
+    genres: Vec<String>,
+}
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-recommendations)
+    pub fn get_recommendations(
+        &self,
+        request: &RecommendationsRequest,
+    ) -> Result<RecommendationsObject, Error> {
+        request.validate()?;
+        self.get("recommendations".to_string(), Some(request.to_query()))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-recommendation-genres)
+    pub fn get_available_genre_seeds(&self) -> Result<Vec<String>, Error> {
+        let genres: Genres = self.get("recommendations/available-genre-seeds".to_string(), None)?;
+        Ok(genres.genres)
+    }
+}
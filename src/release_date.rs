@@ -0,0 +1,164 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::objects::ReleaseDatePrecision;
+use crate::Error;
+
+/// A release date known to the year, the month or the day, such as `1981`, `1981-12` or `1981-12-15`.
+///
+/// Dates are ordered chronologically, with a less precise date ordered before the more precise dates it
+/// contains, so `1981` < `1981-12` < `1981-12-15`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ReleaseDate {
+    year: u16,
+    month: Option<u8>,
+    day: Option<u8>,
+}
+
+impl ReleaseDate {
+    /// Accepts `YYYY`, `YYYY-MM` or `YYYY-MM-DD`.
+    pub fn new(date: &str) -> Result<Self, Error> {
+        let invalid = || {
+            Error::InvalidArgument(format!(
+                "{:?} is not a YYYY, YYYY-MM or YYYY-MM-DD date",
+                date
+            ))
+        };
+
+        let mut parts = date.split('-');
+        let year = parse_part(parts.next(), 4).ok_or_else(invalid)?;
+        let month = match parts.next() {
+            Some(month) => Some(parse_part(Some(month), 2).ok_or_else(invalid)?),
+            None => None,
+        };
+        let day = match parts.next() {
+            Some(day) => Some(parse_part(Some(day), 2).ok_or_else(invalid)?),
+            None => None,
+        };
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+
+        if let Some(month) = month {
+            if !(1..=12).contains(&month) {
+                return Err(invalid());
+            }
+            if let Some(day) = day {
+                if day == 0 || day > days_in_month(year, month) {
+                    return Err(invalid());
+                }
+            }
+        }
+
+        Ok(ReleaseDate {
+            year,
+            month: month.map(|month| month as u8),
+            day: day.map(|day| day as u8),
+        })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> Option<u8> {
+        self.month
+    }
+
+    pub fn day(&self) -> Option<u8> {
+        self.day
+    }
+
+    pub fn precision(&self) -> ReleaseDatePrecision {
+        match (self.month, self.day) {
+            (Some(_), Some(_)) => ReleaseDatePrecision::Day,
+            (Some(_), None) => ReleaseDatePrecision::Month,
+            _ => ReleaseDatePrecision::Year,
+        }
+    }
+
+    /// Whether every day this date may refer to lies within `range`, with both ends of `range` taken as
+    /// wide as their precision allows.
+    ///
+    /// For example `1981-12-15` and `1981` are both within `1981..=1982`, but `1981` is not within
+    /// `1981-06..=1981-12`.
+    pub fn is_within(&self, range: &RangeInclusive<ReleaseDate>) -> bool {
+        range.start().first_day() <= self.first_day() && self.last_day() <= range.end().last_day()
+    }
+
+    fn first_day(&self) -> (u16, u8, u8) {
+        (self.year, self.month.unwrap_or(1), self.day.unwrap_or(1))
+    }
+
+    fn last_day(&self) -> (u16, u8, u8) {
+        let month = self.month.unwrap_or(12);
+        let day = self
+            .day
+            .unwrap_or_else(|| days_in_month(self.year, month as u16) as u8);
+        (self.year, month, day)
+    }
+}
+
+/// Parses a part of a date made of exactly `digits` ASCII digits.
+fn parse_part(part: Option<&str>, digits: usize) -> Option<u16> {
+    let part = part?;
+    if part.len() != digits || !part.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    part.parse().ok()
+}
+
+fn days_in_month(year: u16, month: u16) -> u16 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl fmt::Display for ReleaseDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "-{:02}", month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "-{:02}", day)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ReleaseDate {
+    type Err = Error;
+
+    fn from_str(date: &str) -> Result<Self, Self::Err> {
+        ReleaseDate::new(date)
+    }
+}
+
+impl Serialize for ReleaseDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ReleaseDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let date = String::deserialize(deserializer)?;
+        ReleaseDate::new(&date).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::objects::{
+    ArtistObject, PagingObject, SimplifiedAlbumObject, SimplifiedAudiobookObject,
+    SimplifiedEpisodeObject, SimplifiedPlaylistObject, SimplifiedShowObject, TrackObject,
+};
+use crate::{insert_optional, Error, Market, Spotify};
+
+/// The item types to search across.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SearchType {
+    Album,
+    Artist,
+    Playlist,
+    Track,
+    Show,
+    Episode,
+    Audiobook,
+}
+
+impl SearchType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchType::Album => "album",
+            SearchType::Artist => "artist",
+            SearchType::Playlist => "playlist",
+            SearchType::Track => "track",
+            SearchType::Show => "show",
+            SearchType::Episode => "episode",
+            SearchType::Audiobook => "audiobook",
+        }
+    }
+}
+
+/// Builds a search query string with [field filters](https://developer.spotify.com/documentation/web-api/reference/#endpoint-search) and operators.
+///
+/// Field values are quoted, so `SearchQuery::new().artist("Miles Davis").year_range(1955, 1960)`
+/// produces `artist:"Miles Davis" year:1955-1960`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    terms: Vec<String>,
+}
+
+impl SearchQuery {
+    pub fn new() -> Self {
+        SearchQuery::default()
+    }
+
+    /// Free text matched against every field. Words are matched independently and in any order.
+    pub fn keyword(self, keyword: &str) -> Self {
+        // Each word is its own term, so that `not` negates every word rather than only the first.
+        strip_quotes(keyword)
+            .split_whitespace()
+            .fold(self, |this, word| {
+                if word == "NOT" || word == "OR" {
+                    this.push(quote(word))
+                } else {
+                    this.push(word.to_string())
+                }
+            })
+    }
+
+    /// Free text matched as an exact phrase.
+    pub fn phrase(self, phrase: &str) -> Self {
+        self.push(quote(phrase))
+    }
+
+    pub fn album(self, album: &str) -> Self {
+        self.field("album", album)
+    }
+
+    pub fn artist(self, artist: &str) -> Self {
+        self.field("artist", artist)
+    }
+
+    pub fn track(self, track: &str) -> Self {
+        self.field("track", track)
+    }
+
+    /// Only artists and tracks are filtered by genre.
+    pub fn genre(self, genre: &str) -> Self {
+        self.field("genre", genre)
+    }
+
+    /// Only albums are filtered by [Universal Product Code](https://en.wikipedia.org/wiki/Universal_Product_Code).
+    pub fn upc(self, upc: &str) -> Self {
+        self.field("upc", upc)
+    }
+
+    /// Only tracks are filtered by [International Standard Recording Code](https://en.wikipedia.org/wiki/International_Standard_Recording_Code).
+    pub fn isrc(self, isrc: &str) -> Self {
+        self.field("isrc", isrc)
+    }
+
+    pub fn year(self, year: u16) -> Self {
+        self.push(format!("year:{}", year))
+    }
+
+    /// Both ends of the range are included.
+    pub fn year_range(self, from: u16, to: u16) -> Self {
+        self.push(format!("year:{}-{}", from, to))
+    }
+
+    /// Only albums released in the past two weeks.
+    pub fn tag_new(self) -> Self {
+        self.push("tag:new".to_string())
+    }
+
+    /// Only albums with the lowest 10% popularity.
+    pub fn tag_hipster(self) -> Self {
+        self.push("tag:hipster".to_string())
+    }
+
+    /// Excludes results matching any term of `query`.
+    ///
+    /// `NOT` only applies to the term right after it, so each term is negated on its own.
+    pub fn not(self, query: SearchQuery) -> Self {
+        query
+            .terms
+            .into_iter()
+            .fold(self, |this, term| this.push(format!("NOT {}", term)))
+    }
+
+    /// Places `OR` between the last term added so far and the first term of `query`.
+    ///
+    /// `OR` only applies to the terms either side of it, so any further terms of `query` are still
+    /// required. With no terms added so far, this is `query` itself.
+    pub fn or(self, query: SearchQuery) -> Self {
+        if self.is_empty() {
+            return query;
+        }
+        if query.is_empty() {
+            return self;
+        }
+        self.push(format!("OR {}", query))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    fn field(self, name: &str, value: &str) -> Self {
+        self.push(format!("{}:{}", name, quote(value)))
+    }
+
+    fn push(mut self, term: String) -> Self {
+        if !term.is_empty() {
+            self.terms.push(term);
+        }
+        self
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.terms.join(" "))
+    }
+}
+
+/// The query syntax has no escape sequence, so embedded double quotes are dropped.
+fn strip_quotes(value: &str) -> String {
+    value.replace('"', "")
+}
+
+fn quote(value: &str) -> String {
+    format!("\"{}\"", strip_quotes(value).trim())
+}
+
+/// The paged results of a search, one page per requested [`SearchType`].
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SearchResults {
+    pub albums: Option<PagingObject<SimplifiedAlbumObject>>,
+    pub artists: Option<PagingObject<ArtistObject>>,
+    pub audiobooks: Option<PagingObject<SimplifiedAudiobookObject>>,
+    pub episodes: Option<PagingObject<SimplifiedEpisodeObject>>,
+    pub playlists: Option<PagingObject<SimplifiedPlaylistObject>>,
+    pub shows: Option<PagingObject<SimplifiedShowObject>>,
+    pub tracks: Option<PagingObject<TrackObject>>,
+}
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-search)
+    pub fn search(
+        &self,
+        query: &SearchQuery,
+        types: &[SearchType],
+        market: Option<Market>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<SearchResults, Error> {
+        if query.is_empty() {
+            return Err(Error::InvalidArgument("search query is empty".to_string()));
+        }
+        if types.is_empty() {
+            return Err(Error::InvalidArgument("no search type given".to_string()));
+        }
+
+        let mut params = HashMap::new();
+        params.insert("q", query.to_string());
+        params.insert(
+            "type",
+            types
+                .iter()
+                .map(|t| t.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        insert_optional(&mut params, "market", market);
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "offset", offset);
+
+        self.get("search".to_string(), Some(params))
+    }
+}
@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::objects::{PagingObject, ShowObject, SimplifiedEpisodeObject, SimplifiedShowObject};
+use crate::{insert_optional, Error, Market, Spotify};
+
+/// The most show ids a single request accepts.
+pub const MAX_SHOW_IDS: usize = 50;
+
+#[derive(Deserialize)]
+struct Shows {
+    shows: Vec<Option<SimplifiedShowObject>>,
+}
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-a-show)
+    ///
+    /// The episodes carry the user's `resume_point` if the token has the `user-read-playback-position` scope.
+    pub fn get_show(&self, id: &str, market: Option<Market>) -> Result<ShowObject, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "market", market);
+
+        self.get(format!("shows/{}", id), Some(params))
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-multiple-shows)
+    ///
+    /// Requests the shows [`MAX_SHOW_IDS`] at a time. Shows that are not found are `None`.
+    pub fn get_several_shows(
+        &self,
+        ids: &[&str],
+        market: Option<Market>,
+    ) -> Result<Vec<Option<SimplifiedShowObject>>, Error> {
+        let mut shows = Vec::with_capacity(ids.len());
+        for chunk in ids.chunks(MAX_SHOW_IDS) {
+            let mut params = HashMap::new();
+            params.insert("ids", chunk.join(","));
+            insert_optional(&mut params, "market", market);
+
+            let page: Shows = self.get("shows".to_string(), Some(params))?;
+            shows.extend(page.shows);
+        }
+        Ok(shows)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-a-shows-episodes)
+    pub fn get_show_episodes(
+        &self,
+        id: &str,
+        market: Option<Market>,
+        limit: Option<usize>,
+        offset: Option<usize>,
+    ) -> Result<PagingObject<SimplifiedEpisodeObject>, Error> {
+        let mut params = HashMap::new();
+        insert_optional(&mut params, "market", market);
+        insert_optional(&mut params, "limit", limit);
+        insert_optional(&mut params, "offset", offset);
+
+        self.get(format!("shows/{}/episodes", id), Some(params))
+    }
+}
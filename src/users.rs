@@ -0,0 +1,17 @@
+use crate::objects::{PrivateUserObject, PublicUserObject};
+use crate::{Error, Spotify};
+
+impl Spotify {
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-current-users-profile)
+    ///
+    /// `country`, `explicit_content` and `product` need the `user-read-private` scope and `email` the
+    /// `user-read-email` scope; they are `None` otherwise.
+    pub fn get_current_user_profile(&self) -> Result<PrivateUserObject, Error> {
+        self.get("me".to_string(), None)
+    }
+
+    /// [Reference](https://developer.spotify.com/documentation/web-api/reference/#endpoint-get-users-profile)
+    pub fn get_user_profile(&self, user_id: &str) -> Result<PublicUserObject, Error> {
+        self.get(format!("users/{}", user_id), None)
+    }
+}
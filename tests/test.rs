@@ -1,7 +1,737 @@
 #[cfg(test)]
 mod tests {
+    use std::sync::Mutex;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use serde_json::{json, Value};
+    use spotify_api::body::AddItemsBody;
+    use spotify_api::objects::{
+        AlbumObject, AudioFeaturesObject, AudiobookObject, CategoryObject, ChapterObject,
+        CurrentlyPlayingObject, CursorPagingObject, DeviceType, DevicesObject, EpisodeObject,
+        PagingObject, PlayHistoryObject, PlaylistItemType, PlaylistTrackObject, PrivateUserObject,
+        QueueObject, RestrictionReason, TrackObject, TuneableTrackObject,
+    };
+    use spotify_api::pagination::{fetch_all_with, Cursor};
+    use spotify_api::playlists::{
+        add_items_bodies, encode_cover_image, replace_items_bodies, MAX_COVER_IMAGE_SIZE,
+    };
+    use spotify_api::recommendations::RecommendationsRequest;
+    use spotify_api::search::SearchQuery;
+    use spotify_api::{Error, Market, ReleaseDate, Spotify};
+
     #[test]
     fn it_works() {
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn paging_object_decodes_items() {
+        let page: PagingObject<CategoryObject> = serde_json::from_str(
+            r#"{
+                "href": "https://api.spotify.com/v1/browse/categories?offset=0&limit=1",
+                "items": [{
+                    "href": "https://api.spotify.com/v1/browse/categories/toplists",
+                    "icons": [{"height": 275, "url": "https://t.scdn.co/media/derived/toplists.jpg", "width": 275}],
+                    "id": "toplists",
+                    "name": "Top Lists"
+                }],
+                "limit": 1,
+                "next": "https://api.spotify.com/v1/browse/categories?offset=1&limit=1",
+                "offset": 0,
+                "previous": null,
+                "total": 62
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(page.items[0].id, "toplists");
+        assert!(page.next.is_some());
+        assert_eq!(page.previous, None);
+    }
+
+    #[test]
+    fn cursor_paging_object_decodes_items() {
+        let page: CursorPagingObject<CategoryObject> = serde_json::from_str(
+            r#"{
+                "cursors": {"after": "1617993424000", "before": "1617993104000"},
+                "href": "https://api.spotify.com/v1/me/player/recently-played?limit=20",
+                "items": [],
+                "limit": 20,
+                "next": null
+            }"#,
+        )
+        .unwrap();
+        let cursors = page.cursors.unwrap();
+        assert_eq!(cursors.after.as_deref(), Some("1617993424000"));
+        assert_eq!(cursors.before.as_deref(), Some("1617993104000"));
+        assert_eq!(page.next, None);
+        assert_eq!(page.total, None);
+    }
+
+    #[test]
+    fn search_query_quotes_field_filters() {
+        let query = SearchQuery::new()
+            .artist("Miles Davis")
+            .year_range(1990, 1999)
+            .tag_new()
+            .genre("jazz");
+        assert_eq!(
+            query.to_string(),
+            "artist:\"Miles Davis\" year:1990-1999 tag:new genre:\"jazz\""
+        );
+    }
+
+    #[test]
+    fn search_query_strips_embedded_quotes() {
+        let query = SearchQuery::new()
+            .album("The \"Black\" Album")
+            .keyword("say \"NOT\" now");
+        assert_eq!(
+            query.to_string(),
+            "album:\"The Black Album\" say \"NOT\" now"
+        );
+    }
+
+    #[test]
+    fn search_query_operators() {
+        let query = SearchQuery::new()
+            .keyword("roadhouse")
+            .not(SearchQuery::new().keyword("blues"))
+            .or(SearchQuery::new().isrc("USUM71703861"))
+            .not(SearchQuery::new());
+        assert_eq!(
+            query.to_string(),
+            "roadhouse NOT blues OR isrc:\"USUM71703861\""
+        );
+
+        let query = SearchQuery::new()
+            .keyword("roadhouse")
+            .not(SearchQuery::new().keyword("blues").artist("The Doors"));
+        assert_eq!(
+            query.to_string(),
+            "roadhouse NOT blues NOT artist:\"The Doors\""
+        );
+
+        let query = SearchQuery::new()
+            .keyword("roadhouse")
+            .not(SearchQuery::new().keyword("blues rock"));
+        assert_eq!(query.to_string(), "roadhouse NOT blues NOT rock");
+
+        let query = SearchQuery::new().or(SearchQuery::new().keyword("blues"));
+        assert_eq!(query.to_string(), "blues");
+    }
+
+    #[test]
+    fn recommendations_request_limits_seeds() {
+        let request = RecommendationsRequest::new()
+            .seed_artist("4NHQUGzhtTLFvgF5SZesLK")
+            .seed_genre("classical")
+            .seed_genre("country")
+            .seed_track("0c6xIDDpzE81m2q797ordA");
+        assert!(request.validate().is_ok());
+        assert!(request
+            .clone()
+            .seed_track("1")
+            .seed_track("2")
+            .validate()
+            .is_err());
+        assert!(RecommendationsRequest::new().validate().is_err());
+    }
+
+    #[test]
+    fn recommendations_request_checks_ranges() {
+        let request = RecommendationsRequest::new().seed_genre("jazz");
+        assert!(request
+            .clone()
+            .min_energy(0.4)
+            .max_energy(0.8)
+            .validate()
+            .is_ok());
+        assert!(request.clone().target_valence(1.5).validate().is_err());
+        assert!(request
+            .clone()
+            .target_valence(1.5)
+            .target_valence(0.5)
+            .validate()
+            .is_ok());
+        assert!(request
+            .clone()
+            .min_energy(0.4)
+            .max_energy(0.8)
+            .target_energy(0.9)
+            .validate()
+            .is_err());
+        assert!(request
+            .clone()
+            .min_popularity(50)
+            .target_popularity(40)
+            .validate()
+            .is_err());
+        assert!(request.clone().max_key(12).validate().is_err());
+        assert!(request.clone().target_loudness(-5.88).validate().is_ok());
+        assert!(request
+            .min_tempo(140.0)
+            .max_tempo(120.0)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn queue_keeps_unknown_items() {
+        let queue: QueueObject = serde_json::from_str(
+            r#"{"currently_playing": {"type": "ad"}, "queue": [{"type": "ad"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            queue.currently_playing,
+            Some(PlaylistItemType::Unknown(json!({"type": "ad"})))
+        );
+        assert_eq!(
+            queue.queue,
+            vec![PlaylistItemType::Unknown(json!({"type": "ad"}))]
+        );
+
+        // A track that fails to decode is reported rather than left out.
+        assert!(serde_json::from_str::<QueueObject>(
+            r#"{"currently_playing": null, "queue": [{"type": "track", "id": "4iV5W9uYEdYUVa79Axb7Rh"}]}"#,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn playlist_items_decode_by_type() {
+        type Item = PlaylistItemType<Value, Value, Value>;
+
+        let track = json!({"type": "track", "id": "2takcwOaAZWiXQijPHIx7B"});
+        let item: Item = serde_json::from_value(track.clone()).unwrap();
+        assert_eq!(item, PlaylistItemType::Track(track.clone()));
+        assert_eq!(serde_json::to_value(&item).unwrap(), track);
+
+        let episode = json!({"type": "episode", "id": "512ojhOuo1ktJprKbVcKyQ"});
+        let item: Item = serde_json::from_value(episode.clone()).unwrap();
+        assert_eq!(item, PlaylistItemType::Episode(episode));
+
+        let local = json!({"type": "track", "id": null, "is_local": true});
+        let item: Item = serde_json::from_value(local.clone()).unwrap();
+        assert_eq!(item, PlaylistItemType::Unknown(local));
+
+        let chapter = json!({"type": "chapter", "id": "0D5wENdkdwbqlrHoaJ9g29"});
+        let item: Item = serde_json::from_value(chapter.clone()).unwrap();
+        assert_eq!(item, PlaylistItemType::Chapter(chapter));
+    }
+
+    #[test]
+    fn playlist_item_changes_are_chunked() {
+        let uris: Vec<String> = (0..250).map(|i| format!("spotify:track:{}", i)).collect();
+        let uris: Vec<&str> = uris.iter().map(String::as_str).collect();
+        let shape = |bodies: &[AddItemsBody]| -> Vec<(usize, Option<usize>)> {
+            bodies
+                .iter()
+                .map(|body| (body.uris.len(), body.position))
+                .collect()
+        };
+
+        assert!(add_items_bodies(&[], Some(3)).is_empty());
+        assert_eq!(shape(&add_items_bodies(&uris[..100], None)), [(100, None)]);
+        assert_eq!(
+            shape(&add_items_bodies(&uris[..101], Some(5))),
+            [(100, Some(5)), (1, Some(105))]
+        );
+        let bodies = add_items_bodies(&uris, Some(0));
+        assert_eq!(
+            shape(&bodies),
+            [(100, Some(0)), (100, Some(100)), (50, Some(200))]
+        );
+        assert_eq!(bodies[1].uris[0], "spotify:track:100");
+        assert_eq!(
+            shape(&add_items_bodies(&uris, None)),
+            [(100, None), (100, None), (50, None)]
+        );
+
+        let (replace, rest) = replace_items_bodies(&[]);
+        assert!(replace.uris.is_empty() && rest.is_empty());
+        let (replace, rest) = replace_items_bodies(&uris[..100]);
+        assert_eq!(replace.uris.len(), 100);
+        assert!(rest.is_empty());
+        let (replace, rest) = replace_items_bodies(&uris[..101]);
+        assert_eq!(replace.uris.len(), 100);
+        assert_eq!(shape(&rest), [(1, None)]);
+        assert_eq!(rest[0].uris[0], "spotify:track:100");
+        let (replace, rest) = replace_items_bodies(&uris);
+        assert_eq!(replace.uris.last().unwrap(), "spotify:track:99");
+        assert_eq!(shape(&rest), [(100, None), (50, None)]);
+    }
+
+    #[test]
+    fn cover_image_must_be_small_jpeg() {
+        assert_eq!(
+            encode_cover_image(&[0xFF, 0xD8, 0xFF, 0xE0]).unwrap(),
+            "/9j/4A=="
+        );
+        assert!(encode_cover_image(b"\x89PNG").is_err());
+
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF];
+        jpeg.resize(MAX_COVER_IMAGE_SIZE / 4 * 3, 0);
+        assert!(encode_cover_image(&jpeg).is_ok());
+        jpeg.push(0);
+        assert!(encode_cover_image(&jpeg).is_err());
+    }
+
+    #[test]
+    fn private_user_without_private_scopes() {
+        let user: PrivateUserObject = serde_json::from_str(
+            r#"{
+                "display_name": "JM Wizzler",
+                "external_urls": {"spotify": "https://open.spotify.com/user/wizzler"},
+                "followers": {"href": "", "total": 3829},
+                "href": "https://api.spotify.com/v1/users/wizzler",
+                "id": "wizzler",
+                "images": [],
+                "type": "user",
+                "uri": "spotify:user:wizzler"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(user.id, "wizzler");
+        assert_eq!(user.email, None);
+        assert_eq!(user.product, None);
+    }
+
+    #[test]
+    fn market_codes_are_validated() {
+        assert_eq!(Market::new("se").unwrap().as_str(), "SE");
+        assert_eq!("XK".parse::<Market>().unwrap().to_string(), "XK");
+        assert!(Market::new("from_token").unwrap().is_from_token());
+        assert!(Market::new("ZZ").is_err());
+        assert!(Market::new("SWE").is_err());
+
+        let markets: Vec<Market> = serde_json::from_str(r#"["AD", "from_token"]"#).unwrap();
+        assert_eq!(
+            markets,
+            vec![Market::new("AD").unwrap(), Market::FROM_TOKEN]
+        );
+        assert!(serde_json::from_str::<Vec<Market>>(r#"["ad1"]"#).is_err());
+
+        // Unassigned codes are rejected when built but still decode from a response.
+        let album = include_str!("fixtures/album.json").replace(r#""XK""#, r#""XX""#);
+        let album: AlbumObject = serde_json::from_str(&album).unwrap();
+        assert_eq!(album.available_markets.last().unwrap().as_str(), "XX");
+        assert!(Market::new("XX").is_err());
+    }
+
+    #[test]
+    fn paginator_yields_last_page_without_fetching() {
+        let page: PagingObject<String> = serde_json::from_str(
+            r#"{"href": "", "items": ["a", "b", "c"], "limit": 3, "next": null, "offset": 40, "previous": null, "total": 43}"#,
+        )
+        .unwrap();
+        let spotify = Spotify::new("token");
+        let mut paginator = spotify.paginate(page);
+        assert_eq!(paginator.next().unwrap().unwrap(), "a");
+        assert_eq!(paginator.offset(), 41);
+        let rest: Vec<String> = paginator.map(Result::unwrap).collect();
+        assert_eq!(rest, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn fetch_all_returns_complete_first_page() {
+        let page: PagingObject<usize> = serde_json::from_str(
+            r#"{"href": "https://api.spotify.com/v1/me/tracks?offset=0&limit=50", "items": [1, 2], "limit": 50, "next": null, "offset": 0, "previous": null, "total": 2}"#,
+        )
+        .unwrap();
+        let spotify = Spotify::new("token");
+        assert!(spotify.fetch_all(page.clone(), 0).is_err());
+        assert_eq!(spotify.fetch_all(page, 4).unwrap(), vec![1, 2]);
+    }
+
+    /// The page of `offset` and `offset + 1` out of the numbers 0 to 7.
+    fn number_page(offset: usize) -> Value {
+        json!({"href": "https://api.spotify.com/v1/me/tracks", "items": [offset, offset + 1], "limit": 2, "next": null, "offset": offset, "previous": null, "total": 8})
+    }
+
+    fn page_offset(url: &str) -> usize {
+        let offset = url.split("offset=").nth(1).unwrap();
+        offset.split('&').next().unwrap().parse().unwrap()
+    }
+
+    #[test]
+    fn fetch_all_waits_out_rate_limits_and_keeps_order() {
+        let first: PagingObject<usize> = serde_json::from_value(number_page(0)).unwrap();
+        let start = Instant::now();
+        let calls: Mutex<Vec<(usize, Duration)>> = Mutex::new(Vec::new());
+
+        let items = fetch_all_with(first, 2, |url| {
+            let offset = page_offset(url);
+            let rate_limited = {
+                let mut calls = calls.lock().unwrap();
+                calls.push((offset, start.elapsed()));
+                offset == 2 && calls.iter().filter(|(called, _)| *called == 2).count() == 1
+            };
+            if rate_limited {
+                return Err(Error::RateLimited(Duration::from_millis(300)));
+            }
+            if offset == 4 {
+                thread::sleep(Duration::from_millis(100));
+            }
+            Ok(number_page(offset))
+        })
+        .unwrap();
+
+        // The page at offset 4 completes first, yet every item comes back in order.
+        assert_eq!(items, (0..8).collect::<Vec<_>>());
+        let calls = calls.into_inner().unwrap();
+        assert_eq!(calls.iter().filter(|(offset, _)| *offset == 2).count(), 2);
+        // The worker that fetched offset 4 also waits out the rate limit before taking offset 6.
+        let (_, called_at) = calls.iter().find(|(offset, _)| *offset == 6).unwrap();
+        assert!(*called_at >= Duration::from_millis(250), "{:?}", called_at);
+    }
+
+    #[test]
+    fn fetch_all_stops_at_the_first_error() {
+        let first: PagingObject<usize> = serde_json::from_value(number_page(0)).unwrap();
+        let calls = Mutex::new(Vec::new());
+        let result = fetch_all_with(first, 1, |url| {
+            let offset = page_offset(url);
+            calls.lock().unwrap().push(offset);
+            if offset == 4 {
+                return Err(Error::InvalidArgument("no such page".to_string()));
+            }
+            Ok(number_page(offset))
+        });
+
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+        assert_eq!(calls.into_inner().unwrap(), [2, 4]);
+    }
+
+    #[test]
+    fn fetch_all_unwraps_wrapped_pages() {
+        let first: PagingObject<usize> = serde_json::from_value(number_page(0)).unwrap();
+        let items = fetch_all_with(first, 2, |url| {
+            let offset = page_offset(url);
+            // Shaped like the featured playlists, whose page sits next to a message.
+            Ok(json!({"message": "Good evening", "playlists": number_page(offset)}))
+        })
+        .unwrap();
+        assert_eq!(items, (0..8).collect::<Vec<_>>());
+
+        let first: PagingObject<usize> = serde_json::from_value(number_page(0)).unwrap();
+        let items = fetch_all_with(first, 1, |url| {
+            Ok(json!({"artists": number_page(page_offset(url))}))
+        });
+        assert_eq!(items.unwrap(), (0..8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn paginator_cursor_follows_next_link() {
+        let page: CursorPagingObject<usize> = serde_json::from_str(
+            r#"{
+                "cursors": {"after": "1700000900000", "before": "1700000500000"},
+                "href": "https://api.spotify.com/v1/me/player/recently-played?before=1700001000000&limit=3",
+                "items": [1, 2, 3],
+                "limit": 3,
+                "next": "https://api.spotify.com/v1/me/player/recently-played?before=1700000500000&limit=3"
+            }"#,
+        )
+        .unwrap();
+        let spotify = Spotify::new("token");
+        let mut paginator = spotify.paginate_cursor(page);
+        let current = Cursor::Before("1700001000000".to_string());
+        assert_eq!(paginator.cursor(), Some(&current));
+
+        // Stopping part-way through the page resumes from its start rather than skipping the rest.
+        assert_eq!(paginator.next().unwrap().unwrap(), 1);
+        assert_eq!(paginator.next().unwrap().unwrap(), 2);
+        assert_eq!(paginator.cursor(), Some(&current));
+
+        assert_eq!(paginator.next().unwrap().unwrap(), 3);
+        let cursor = paginator.cursor().unwrap();
+        assert_eq!(cursor, &Cursor::Before("1700000500000".to_string()));
+        assert_eq!(cursor.before_timestamp(), Some(1_700_000_500_000));
+        assert_eq!(cursor.after_timestamp(), None);
+    }
+
+    #[test]
+    fn remaining_page_urls_plan_every_offset() {
+        let mut page: PagingObject<usize> = serde_json::from_str(
+            r#"{"href": "https://api.spotify.com/v1/me/tracks?offset=0&limit=2&market=SE", "items": [1, 2], "limit": 2, "next": null, "offset": 0, "previous": null, "total": 7}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            page.remaining_page_urls().unwrap(),
+            [
+                "https://api.spotify.com/v1/me/tracks?market=SE&offset=2&limit=2",
+                "https://api.spotify.com/v1/me/tracks?market=SE&offset=4&limit=2",
+                "https://api.spotify.com/v1/me/tracks?market=SE&offset=6&limit=2",
+            ]
+        );
+
+        page.total = 2;
+        assert!(page.remaining_page_urls().unwrap().is_empty());
+        page.total = 7;
+        page.href = "me/tracks".to_string();
+        assert!(page.remaining_page_urls().is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn timestamps_sort_chronologically() {
+        use spotify_api::objects::Timestamp;
+
+        let earlier: Timestamp = serde_json::from_str(r#""2021-05-05T18:55:10Z""#).unwrap();
+        let later: Timestamp = serde_json::from_str(r#""2021-11-30T07:02:41Z""#).unwrap();
+        assert!(earlier < later);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn play_history_decodes_played_at() {
+        use chrono::{TimeZone, Utc};
+
+        let track: Value = serde_json::from_str(include_str!("fixtures/track.json")).unwrap();
+        let history: PlayHistoryObject = serde_json::from_value(json!({
+            "context": null,
+            "played_at": "2021-05-05T18:55:10.123Z",
+            "track": track,
+        }))
+        .unwrap();
+        let played_at = Utc.with_ymd_and_hms(2021, 5, 5, 18, 55, 10).unwrap()
+            + chrono::Duration::milliseconds(123);
+        assert_eq!(history.played_at, played_at);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn currently_playing_decodes_millisecond_timestamp() {
+        use chrono::{TimeZone, Utc};
+
+        let playing: CurrentlyPlayingObject = serde_json::from_str(
+            r#"{"timestamp": 1620240910123, "context": null, "progress_ms": null, "item": null, "currently_playing_type": "ad", "is_playing": true}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            playing.timestamp,
+            Utc.timestamp_millis_opt(1_620_240_910_123).unwrap()
+        );
+        assert_eq!(
+            playing.timestamp,
+            Utc.with_ymd_and_hms(2021, 5, 5, 18, 55, 10).unwrap()
+                + chrono::Duration::milliseconds(123)
+        );
+        assert_eq!(
+            serde_json::to_value(&playing).unwrap()["timestamp"],
+            json!(1_620_240_910_123u64)
+        );
+    }
+
+    #[test]
+    fn audio_features_round_trip() {
+        let json = r#"{
+            "acousticness": 0.00242,
+            "analysis_url": "https://api.spotify.com/v1/audio-analysis/2takcwOaAZWiXQijPHIx7B",
+            "danceability": 0.585,
+            "duration_ms": 237040,
+            "energy": 0.842,
+            "id": "2takcwOaAZWiXQijPHIx7B",
+            "instrumentalness": 0.00686,
+            "key": 9,
+            "liveness": 0.0866,
+            "loudness": -5.883,
+            "mode": 0,
+            "speechiness": 0.0556,
+            "tempo": 118.211,
+            "time_signature": 4,
+            "track_href": "https://api.spotify.com/v1/tracks/2takcwOaAZWiXQijPHIx7B",
+            "type": "audio_features",
+            "uri": "spotify:track:2takcwOaAZWiXQijPHIx7B",
+            "valence": 0.428
+        }"#;
+        let features: AudioFeaturesObject = serde_json::from_str(json).unwrap();
+        assert_eq!(features.loudness, -5.883);
+        assert_eq!(features.key, 9);
+
+        let original: serde_json::Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_value(&features).unwrap(), original);
+
+        let unknown_key = json.replace(r#""key": 9"#, r#""key": -1"#);
+        let features: AudioFeaturesObject = serde_json::from_str(&unknown_key).unwrap();
+        assert_eq!(features.key, -1);
+    }
+
+    #[test]
+    fn tuneable_track_round_trip() {
+        let json = r#"{
+            "acousticness": 0.5,
+            "danceability": 0.75,
+            "duration_ms": 210000,
+            "energy": 0.6,
+            "instrumentalness": 0.1,
+            "key": 4,
+            "liveness": 0.2,
+            "loudness": -7.5,
+            "mode": 1,
+            "popularity": 62.5,
+            "speechiness": 0.05,
+            "tempo": 120.0,
+            "time_signature": 4,
+            "valence": 0.8
+        }"#;
+        let tuneable: TuneableTrackObject = serde_json::from_str(json).unwrap();
+        assert_eq!(tuneable.popularity, 62.5);
+        assert_eq!(tuneable.tempo, 120.0);
+
+        let original: Value = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_value(&tuneable).unwrap(), original);
+    }
+
+    #[test]
+    fn nullable_fields_decode_as_none() {
+        let playing: CurrentlyPlayingObject = serde_json::from_str(
+            r#"{"timestamp": 1620240910000, "context": null, "progress_ms": null, "item": null, "currently_playing_type": "ad", "is_playing": true}"#,
+        )
+        .unwrap();
+        assert!(playing.context.is_none());
+        assert!(playing.item.is_none());
+        assert!(playing.progress_ms.is_none());
+
+        let devices: DevicesObject = serde_json::from_str(
+            r#"{"devices": [{"id": null, "is_active": false, "is_private_session": false, "is_restricted": true, "name": "Living Room", "type": "Speaker", "volume_percent": null}]}"#,
+        )
+        .unwrap();
+        assert_eq!(devices.devices[0].id, None);
+        assert_eq!(devices.devices[0].volume_percent, None);
+    }
+
+    #[test]
+    fn string_enums_keep_unknown_values() {
+        let known: DeviceType = serde_json::from_str(r#""Smartphone""#).unwrap();
+        assert_eq!(known, DeviceType::Smartphone);
+
+        let unknown: DeviceType = serde_json::from_str(r#""Hologram""#).unwrap();
+        assert_eq!(unknown, DeviceType::Other("Hologram".to_string()));
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), r#""Hologram""#);
+    }
+
+    #[test]
+    fn release_dates_parse_order_and_filter() {
+        let year = ReleaseDate::new("1981").unwrap();
+        let month = ReleaseDate::new("1981-12").unwrap();
+        let day = ReleaseDate::new("1981-12-15").unwrap();
+        assert_eq!(
+            (day.year(), day.month(), day.day()),
+            (1981, Some(12), Some(15))
+        );
+        assert_eq!(month.day(), None);
+        assert!(year < month && month < day && day < ReleaseDate::new("1982").unwrap());
+
+        for date in ["1981", "1981-12", "1981-12-15", "0000"] {
+            let json = format!("{:?}", date);
+            let parsed: ReleaseDate = serde_json::from_str(&json).unwrap();
+            assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        }
+        for date in [
+            "81",
+            "1981-13",
+            "1981-02-29",
+            "1981-1-5",
+            "1981-12-15-01",
+            "",
+        ] {
+            assert!(ReleaseDate::new(date).is_err(), "{}", date);
+        }
+        assert!(ReleaseDate::new("1984-02-29").is_ok());
+
+        let eighties = ReleaseDate::new("1980").unwrap()..=ReleaseDate::new("1989").unwrap();
+        assert!(year.is_within(&eighties) && day.is_within(&eighties));
+        let second_half = ReleaseDate::new("1981-07").unwrap()..=month;
+        assert!(day.is_within(&second_half));
+        assert!(!year.is_within(&second_half));
+    }
+
+    #[test]
+    fn captured_track_decodes() {
+        let track: TrackObject = serde_json::from_str(include_str!("fixtures/track.json")).unwrap();
+        assert_eq!(track.artists.len(), 2);
+        assert_eq!(track.artists[1].name, "Sensato");
+        assert_eq!(track.album.artists[0].id, "0TnOYISbd1XYRBk9myaseg");
+        assert!(track.available_markets.is_empty());
+        assert_eq!(track.preview_url, None);
+    }
+
+    #[test]
+    fn play_history_holds_full_track() {
+        let track: Value = serde_json::from_str(include_str!("fixtures/track.json")).unwrap();
+        let history: PlayHistoryObject = serde_json::from_value(json!({
+            "context": null,
+            "played_at": "2021-05-05T18:55:10Z",
+            "track": track,
+        }))
+        .unwrap();
+        assert_eq!(history.track.album.name, "Global Warming");
+        assert_eq!(history.track.popularity, 60);
+    }
+
+    #[test]
+    fn captured_album_decodes() {
+        let album: AlbumObject = serde_json::from_str(include_str!("fixtures/album.json")).unwrap();
+        assert_eq!(album.artists[0].name, "Various Artists");
+        assert_eq!(album.release_date, ReleaseDate::new("1961").unwrap());
+        assert_eq!(album.tracks.total, 2);
+        let names: Vec<_> = album.tracks.items.iter().map(|track| &track.name).collect();
+        assert_eq!(names, ["Little Old Lady", "Village Blues"]);
+        assert_eq!(album.tracks.items[0].artists[0].name, "John Coltrane");
+    }
+
+    #[test]
+    fn captured_episode_decodes() {
+        let episode: EpisodeObject =
+            serde_json::from_str(include_str!("fixtures/episode.json")).unwrap();
+        assert_eq!(episode.name, "Fresh Insights");
+        assert_eq!(episode.show.publisher, "Spotify");
+        assert_eq!(episode.show.id, "38bS44xjbVVZ3No3ByF1dJ");
+    }
+
+    #[test]
+    fn captured_playlist_item_decodes() {
+        let item: PlaylistTrackObject =
+            serde_json::from_str(include_str!("fixtures/playlist_item.json")).unwrap();
+        assert_eq!(item.added_by.unwrap().id, "wizzler");
+        match item.track {
+            Some(PlaylistItemType::Track(track)) => {
+                assert_eq!(track.name, "Global Warming (feat. Sensato)")
+            }
+            other => panic!("expected a track, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn captured_audiobook_decodes() {
+        let audiobook: AudiobookObject =
+            serde_json::from_str(include_str!("fixtures/audiobook.json")).unwrap();
+        assert_eq!(audiobook.authors[0].name, "Frank Herbert");
+        assert_eq!(audiobook.narrators.len(), 2);
+        assert_eq!(audiobook.edition.as_deref(), Some("Unabridged"));
+        assert_eq!(audiobook.chapters.total, 2);
+        assert_eq!(audiobook.chapters.items[1].chapter_number, 1);
+        assert_eq!(
+            audiobook.chapters.items[1]
+                .restrictions
+                .as_ref()
+                .unwrap()
+                .reason,
+            RestrictionReason::PaymentRequired
+        );
+    }
+
+    #[test]
+    fn captured_chapter_decodes() {
+        let json = include_str!("fixtures/chapter.json");
+        let chapter: ChapterObject = serde_json::from_str(json).unwrap();
+        assert_eq!(chapter.name, "Book One: Dune");
+        assert_eq!(chapter.release_date, ReleaseDate::new("1965").unwrap());
+        assert_eq!(chapter.audiobook.id, "7iHfbu1YPACw6oZPAFJtqe");
+
+        type Item = PlaylistItemType<TrackObject, EpisodeObject>;
+        let item: Item = serde_json::from_str(json).unwrap();
+        assert_eq!(item, PlaylistItemType::Chapter(chapter));
+    }
 }